@@ -0,0 +1,122 @@
+/*
+ * C interface of algorand-vanity, built with `cargo build --release --features ffi`
+ * into target/release/libalgorand_vanity.so (.dylib on macOS, .dll on Windows).
+ *
+ * Ownership: keys, public keys and addresses are written to buffers owned by the caller.
+ * Strings returned by av_mnemonic_from_key belong to the caller and must be released with
+ * av_string_free. Searches returned by av_search_start must be released with av_search_free.
+ * Strings passed in are NUL terminated UTF-8 and only read during the call.
+ */
+
+#ifndef ALGORAND_VANITY_H
+#define ALGORAND_VANITY_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define AV_SEED_LEN 32
+#define AV_PUBLIC_KEY_LEN 32
+#define AV_ADDRESS_LEN 58
+
+typedef enum {
+    AV_OK = 0,
+    /* A null pointer, a string which is not UTF-8, an unknown option or a search without patterns */
+    AV_INVALID_ARGUMENT = 1,
+    AV_INVALID_MNEMONIC = 2,
+    AV_INVALID_ADDRESS = 3,
+    AV_INVALID_PATTERN = 4,
+    /* av_search_poll found no match within its timeout */
+    AV_TIMEOUT = 5,
+    /* The search has ended and every match has been polled */
+    AV_SEARCH_ENDED = 6,
+    /* The search stopped on an error after every match found before it has been polled,
+     * av_search_error tells why */
+    AV_SEARCH_FAILED = 7,
+} av_status;
+
+typedef enum {
+    /* Anchors, fixed positions, wildcards and classes as described in the README */
+    AV_PATTERN = 0,
+    /* The start of the address */
+    AV_PREFIX = 1,
+    /* The end of the address */
+    AV_SUFFIX = 2,
+} av_pattern_type;
+
+typedef struct {
+    const char *const *patterns;
+    /* At least 1, a search without patterns could never find a match */
+    size_t pattern_count;
+    /* One of av_pattern_type, fixed to 32 bits as the size of an enum depends on the compiler */
+    uint32_t pattern_type;
+    /* 0 uses every logical CPU */
+    size_t threads;
+    /* Distinct matches to find, 0 for no limit */
+    size_t count;
+    /* 0 for no limit */
+    double max_seconds;
+    /* 0 for no limit */
+    uint64_t max_attempts;
+} av_search_options;
+
+typedef struct {
+    /* Index of the matched pattern in av_search_options.patterns */
+    size_t pattern;
+    uint8_t secret_key[AV_SEED_LEN];
+    uint8_t public_key[AV_PUBLIC_KEY_LEN];
+    /* NUL terminated */
+    char address[AV_ADDRESS_LEN + 1];
+    uint64_t attempts;
+    double elapsed_seconds;
+} av_match;
+
+typedef struct AvSearch av_search;
+
+/* Derives the public key of a private key seed */
+av_status av_public_key(const uint8_t seed[AV_SEED_LEN], uint8_t public_key[AV_PUBLIC_KEY_LEN]);
+
+/* Writes the address of a public key as a NUL terminated string */
+av_status av_encode_address(const uint8_t public_key[AV_PUBLIC_KEY_LEN], char address[AV_ADDRESS_LEN + 1]);
+
+/* Reads the public key of an address, verifying its checksum */
+av_status av_decode_address(const char *address, uint8_t public_key[AV_PUBLIC_KEY_LEN]);
+
+/* Returns the 25 word mnemonic of a private key seed, to be released with av_string_free,
+ * or NULL if key is NULL */
+char *av_mnemonic_from_key(const uint8_t key[AV_SEED_LEN]);
+
+/* Reads the private key seed of a 25 word mnemonic, verifying its checksum */
+av_status av_mnemonic_to_key(const char *mnemonic, uint8_t key[AV_SEED_LEN]);
+
+/* Releases a string returned by this library, NULL is ignored */
+void av_string_free(char *text);
+
+/* Starts a search on CPU threads and stores its handle in *search.
+ * Returns AV_INVALID_ARGUMENT if options has no patterns. */
+av_status av_search_start(const av_search_options *options, av_search **search);
+
+/* Waits up to timeout_ms milliseconds for the next match and writes it to *found.
+ * Returns AV_TIMEOUT if none came in time, and AV_SEARCH_ENDED or AV_SEARCH_FAILED once the search is over. */
+av_status av_search_poll(const av_search *search, uint32_t timeout_ms, av_match *found);
+
+/* Returns why a search failed, to be released with av_string_free,
+ * or NULL if it has not ended with AV_SEARCH_FAILED */
+char *av_search_error(const av_search *search);
+
+/* Stops a search, av_search_poll then returns the matches already found followed by AV_SEARCH_ENDED.
+ * Can be called from any thread, also while another one is polling. */
+void av_search_cancel(const av_search *search);
+
+/* Cancels the search if it is still running and releases it, NULL is ignored.
+ * No other thread may use the search anymore. */
+void av_search_free(av_search *search);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,119 @@
+"""Tests of the algorand_vanity extension module
+
+Run with `python -m unittest discover python/tests` after `maturin develop`.
+Addresses are checked against an independent encoding in Python, and public keys against the
+`cryptography` package if it is installed.
+"""
+
+import base64
+import hashlib
+import os
+import threading
+import time
+import unittest
+
+import algorand_vanity
+
+try:
+    from cryptography.hazmat.primitives.asymmetric.ed25519 import Ed25519PrivateKey
+    from cryptography.hazmat.primitives.serialization import Encoding, PublicFormat
+except ImportError:
+    Ed25519PrivateKey = None
+
+
+def encode_address(public_key):
+    checksum = hashlib.new("sha512_256", public_key).digest()[-4:]
+    return base64.b32encode(public_key + checksum).decode().rstrip("=")
+
+
+def public_key_of(seed):
+    key = Ed25519PrivateKey.from_private_bytes(seed)
+    return key.public_key().public_bytes(Encoding.Raw, PublicFormat.Raw)
+
+
+class KeyTest(unittest.TestCase):
+    def test_address_matches_python_encoding(self):
+        for _ in range(20):
+            kp = algorand_vanity.KeyPair.from_seed(os.urandom(32))
+            self.assertEqual(kp.address, encode_address(kp.public_key))
+            self.assertEqual(algorand_vanity.encode_address(kp.public_key), kp.address)
+            self.assertEqual(algorand_vanity.decode_address(kp.address), kp.public_key)
+
+    @unittest.skipIf(Ed25519PrivateKey is None, "cryptography is not installed")
+    def test_public_key_matches_cryptography(self):
+        for _ in range(20):
+            seed = os.urandom(32)
+            kp = algorand_vanity.KeyPair.from_seed(seed)
+            self.assertEqual(kp.secret_key, seed)
+            self.assertEqual(kp.public_key, public_key_of(seed))
+
+    def test_mnemonic_round_trip(self):
+        seed = os.urandom(32)
+        phrase = algorand_vanity.mnemonic_from_key(seed)
+        self.assertEqual(len(phrase.split()), 25)
+        self.assertEqual(algorand_vanity.mnemonic_to_key(phrase), seed)
+        kp = algorand_vanity.KeyPair.from_mnemonic(phrase)
+        self.assertEqual(kp.secret_key, seed)
+        self.assertEqual(kp.mnemonic, phrase)
+
+    def test_invalid_input(self):
+        with self.assertRaises(ValueError):
+            algorand_vanity.KeyPair.from_seed(b"short")
+        words = algorand_vanity.mnemonic_from_key(bytes(range(32))).split()
+        words[0], words[1] = words[1], words[0]
+        with self.assertRaises(ValueError):
+            algorand_vanity.mnemonic_to_key(" ".join(words))
+        address = algorand_vanity.KeyPair.from_seed(bytes(32)).address
+        with self.assertRaises(ValueError):
+            algorand_vanity.decode_address(("B" if address[0] == "A" else "A") + address[1:])
+
+
+class SearchTest(unittest.TestCase):
+    def test_matches_are_valid(self):
+        matches = algorand_vanity.search(["AB"], pattern_type="prefix", count=3, threads=2)
+        self.assertEqual(len(matches), 3)
+        self.assertEqual(len({found.address for found in matches}), 3)
+        for found in matches:
+            self.assertTrue(found.address.startswith("AB"))
+            self.assertEqual(found.pattern, "AB")
+            self.assertEqual(found.address, encode_address(found.key_pair.public_key))
+            self.assertEqual(algorand_vanity.mnemonic_to_key(found.mnemonic), found.key_pair.secret_key)
+            if Ed25519PrivateKey is not None:
+                self.assertEqual(found.key_pair.public_key, public_key_of(found.key_pair.secret_key))
+
+    def test_suffix(self):
+        [found] = algorand_vanity.search(["Q"], pattern_type="suffix", threads=1)
+        self.assertTrue(found.address.endswith("Q"))
+
+    def test_cancel_from_another_thread(self):
+        # A prefix of 12 characters is practically never found, so only cancelling ends the search
+        searcher = algorand_vanity.Searcher(["AAAAAAAAAAAA"], pattern_type="prefix", threads=1)
+        timer = threading.Timer(0.5, searcher.cancel)
+        start = time.monotonic()
+        timer.start()
+        self.assertEqual(searcher.run(), [])
+        self.assertLess(time.monotonic() - start, 10)
+        with self.assertRaises(ValueError):
+            searcher.run()
+
+    def test_max_time(self):
+        start = time.monotonic()
+        matches = algorand_vanity.search(["AAAAAAAAAAAA"], pattern_type="prefix", count=None, threads=1, max_time=1)
+        self.assertEqual(matches, [])
+        self.assertLess(time.monotonic() - start, 10)
+
+    def test_invalid_pattern(self):
+        with self.assertRaises(ValueError):
+            algorand_vanity.Searcher(["A0"])
+        with self.assertRaises(ValueError):
+            algorand_vanity.Searcher(["A"], pattern_type="infix")
+
+    def test_no_patterns(self):
+        with self.assertRaisesRegex(ValueError, "at least one pattern is required"):
+            algorand_vanity.search([])
+        with self.assertRaisesRegex(ValueError, "at least one pattern is required"):
+            algorand_vanity.Searcher([], count=None)
+
+
+if __name__ == "__main__":
+    unittest.main()
@@ -0,0 +1,426 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::checkpoint::{Checkpoint, Worker};
+use crate::crypto::KeyPair;
+use crate::difficulty::Difficulty;
+use crate::matcher::Matcher;
+use crate::pattern::Pattern;
+use crate::search::{Job, Summary};
+use crate::seeds::Streams;
+
+/// How long `run` waits for a backend before reporting progress and checking the limits
+const POLL_INTERVAL: Duration = Duration::from_secs(1);
+/// How many seconds to remember for the key rate
+const RATE_WINDOW: Duration = Duration::from_secs(8);
+
+/// A seed and its address, known to be right, for the self-tests of the backends
+pub use crate::test_vectors::{ADDRESS as TEST_ADDRESS, SEED as TEST_SEED};
+
+/// A key whose address matches one of the patterns
+pub struct Found {
+    /// Index of the pattern it matches
+    pub pattern: usize,
+    pub key_pair: KeyPair,
+    /// Keys tried since the last poll up to and including this one, as far as the backend knows:
+    /// all keys of the stream which found it, but only the counted ones of the others
+    pub attempts: u64,
+}
+
+/// What a backend did since it was last polled
+#[derive(Default)]
+pub struct Update {
+    /// Keys tried since the last poll
+    pub attempts: u64,
+    /// Position of every stream the backend draws from, empty if unchanged
+    pub positions: Vec<Worker>,
+    pub matches: Vec<Found>,
+    /// Hits which turned out wrong when checked on the CPU
+    pub faults: Vec<String>,
+}
+
+/// A device searching for keys, driven by `run`
+///
+/// Backends only produce candidates. Progress, output, limits and checkpoints are handled by `run`
+/// the same way for all of them.
+pub trait SearchBackend {
+    /// Where the search runs, such as "8 CPU threads"
+    fn device(&self) -> String;
+
+    /// Whether the backend can search for all of the patterns, explaining why not
+    fn supports(&self, patterns: &[Pattern]) -> Result<(), String>;
+
+    /// Checks that the backend derives the right keys and finds their addresses
+    fn self_test(&mut self) -> Result<(), String>;
+
+    /// Starts searching in the background, drawing seeds from `streams` after the positions saved in `resume`
+    fn start(&mut self, matcher: Arc<Matcher>, streams: Streams, resume: &Checkpoint) -> Result<(), String>;
+
+    /// Waits up to `timeout` and returns what happened since the last poll, returning early on a match
+    fn poll(&mut self, timeout: Duration) -> Result<Update, String>;
+
+    /// Stops the search
+    fn cancel(&mut self);
+}
+
+/// Keys per second over the last `RATE_WINDOW`
+struct Rate {
+    samples: VecDeque<(Instant, u64)>,
+}
+
+impl Rate {
+    fn new(attempts: u64) -> Rate {
+        Rate { samples: VecDeque::from([(Instant::now(), attempts)]) }
+    }
+
+    fn update(&mut self, attempts: u64) -> f64 {
+        let now = Instant::now();
+        self.samples.push_back((now, attempts));
+        while self.samples.len() > 2 && now.duration_since(self.samples[0].0) > RATE_WINDOW {
+            self.samples.pop_front();
+        }
+        let (since, before) = self.samples[0];
+        let seconds = now.duration_since(since).as_secs_f64();
+        if seconds > 0.0 { (attempts - before) as f64 / seconds } else { 0.0 }
+    }
+}
+
+/// Searches on `backend` until one of the limits of the `job` is reached
+///
+/// Checks that the backend supports the patterns and passes its self-test before starting it,
+/// then reports its progress and every distinct match, and saves checkpoints along the way.
+pub fn run(backend: &mut dyn SearchBackend, matcher: Matcher, job: Job) -> Result<Summary, String> {
+    let Job { limits, reporter, streams, mut checkpointer } = job;
+    backend.supports(matcher.patterns())?;
+    backend.self_test().map_err(|err| format!("Self-test failed: {}", err))?;
+    let resume = checkpointer.initial().clone();
+    let difficulty = Difficulty::of(matcher.patterns());
+    let matcher = Arc::new(matcher);
+    backend.start(matcher.clone(), streams, &resume)?;
+
+    let mut attempts = resume.attempts;
+    let mut found: HashSet<String> = resume.matches.iter().cloned().collect();
+    let mut positions = resume.workers.clone();
+    let mut faults = 0;
+    let mut rate = Rate::new(attempts);
+    let start = Instant::now();
+    let mut save = |force: bool, attempts: u64, elapsed: Duration, found: &HashSet<String>, positions: &[Worker]| {
+        let saved = checkpointer.update(force, || Checkpoint {
+            patterns: resume.patterns.clone(),
+            workers: positions.to_vec(),
+            attempts,
+            elapsed_seconds: elapsed.as_secs_f64(),
+            matches: found.iter().cloned().collect(),
+        });
+        if let Err(err) = saved {
+            reporter.warning(&format!("Failed to save the checkpoint: {}", err));
+        }
+    };
+    reporter.started(&backend.device());
+    loop {
+        let update = match backend.poll(POLL_INTERVAL) {
+            Ok(update) => update,
+            Err(err) => {
+                backend.cancel();
+                return Err(err);
+            }
+        };
+        let before = attempts;
+        attempts += update.attempts;
+        if !update.positions.is_empty() {
+            positions = update.positions;
+        }
+        let elapsed = resume.elapsed() + start.elapsed();
+        for fault in &update.faults {
+            faults += 1;
+            reporter.kernel_fault(faults, fault);
+        }
+        reporter.progress(attempts, elapsed, rate.update(attempts), &difficulty);
+        // report the matches which came in, skipping duplicates
+        for hit in update.matches {
+            if !found.insert(hit.key_pair.address()) {
+                continue;
+            }
+            reporter.found(hit.pattern, matcher.pattern(hit.pattern), &hit.key_pair, before + hit.attempts, elapsed);
+            if limits.enough_matches(found.len()) {
+                backend.cancel();
+                save(true, attempts, elapsed, &found, &positions);
+                return Ok(Summary { matches: found.len(), attempts, elapsed });
+            }
+        }
+        if limits.exhausted(attempts, elapsed) {
+            backend.cancel();
+            save(true, attempts, elapsed, &found, &positions);
+            let summary = Summary { matches: found.len(), attempts, elapsed };
+            reporter.stopped(&summary);
+            return Ok(summary);
+        }
+        save(false, attempts, elapsed, &found, &positions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::path::PathBuf;
+    use crate::checkpoint::Checkpointer;
+    use crate::search::{Limits, Reporter};
+    use super::*;
+
+    /// Plays back a fixed list of updates, then reports nothing but `idle_attempts` per poll
+    #[derive(Default)]
+    struct MockBackend {
+        updates: VecDeque<Update>,
+        idle_attempts: u64,
+        self_test: Option<String>,
+        started: bool,
+        cancelled: bool,
+        polls: usize,
+    }
+
+    impl SearchBackend for MockBackend {
+        fn device(&self) -> String {
+            "mock".to_string()
+        }
+
+        fn supports(&self, patterns: &[Pattern]) -> Result<(), String> {
+            match patterns.len() {
+                1 => Ok(()),
+                _ => Err("The mock backend only supports a single pattern".to_string()),
+            }
+        }
+
+        fn self_test(&mut self) -> Result<(), String> {
+            self.self_test.clone().map_or(Ok(()), Err)
+        }
+
+        fn start(&mut self, _matcher: Arc<Matcher>, _streams: Streams, _resume: &Checkpoint) -> Result<(), String> {
+            self.started = true;
+            Ok(())
+        }
+
+        fn poll(&mut self, _timeout: Duration) -> Result<Update, String> {
+            assert!(self.started && !self.cancelled, "polled a backend which is not running");
+            self.polls += 1;
+            Ok(self.updates.pop_front().unwrap_or(Update { attempts: self.idle_attempts, ..Update::default() }))
+        }
+
+        fn cancel(&mut self) {
+            self.cancelled = true;
+        }
+    }
+
+    /// Records every report as a line of text
+    #[derive(Default)]
+    struct Recorder {
+        events: RefCell<Vec<String>>,
+    }
+
+    impl Recorder {
+        fn events(&self, kind: &str) -> Vec<String> {
+            self.events.borrow().iter().filter(|event| event.starts_with(kind)).cloned().collect()
+        }
+    }
+
+    impl Reporter for Recorder {
+        fn started(&self, device: &str) {
+            self.events.borrow_mut().push(format!("started {}", device));
+        }
+
+        fn progress(&self, attempts: u64, _elapsed: Duration, _keys_per_second: f64, _difficulty: &Difficulty) {
+            self.events.borrow_mut().push(format!("progress {}", attempts));
+        }
+
+        fn found(&self, _index: usize, pattern: &Pattern, kp: &KeyPair, attempts: u64, _elapsed: Duration) {
+            self.events.borrow_mut().push(format!("found {} {} {}", pattern, kp.address(), attempts));
+        }
+
+        fn kernel_fault(&self, number: usize, fault: &str) {
+            self.events.borrow_mut().push(format!("fault {} {}", number, fault));
+        }
+
+        fn warning(&self, message: &str) {
+            self.events.borrow_mut().push(format!("warning {}", message));
+        }
+
+        fn stopped(&self, summary: &Summary) {
+            self.events.borrow_mut().push(format!("stopped {} {}", summary.matches, summary.attempts));
+        }
+    }
+
+    fn key(byte: u8) -> KeyPair {
+        KeyPair::from_seed([byte; 32])
+    }
+
+    fn found(byte: u8, attempts: u64) -> Found {
+        Found { pattern: 0, key_pair: key(byte), attempts }
+    }
+
+    fn matcher() -> Matcher {
+        Matcher::new(vec![Pattern::parse("?").unwrap()])
+    }
+
+    fn job<'a>(limits: Limits, reporter: &'a Recorder, checkpointer: Checkpointer) -> Job<'a> {
+        Job { limits, reporter, streams: Streams { key: [0; 32], first: 0 }, checkpointer }
+    }
+
+    fn no_checkpoint() -> Checkpointer {
+        Checkpointer::new(None, Checkpoint::new(matcher().patterns()))
+    }
+
+    fn checkpoint_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("algorand-vanity-{}-{}.json", name, std::process::id()))
+    }
+
+    #[test]
+    fn stops_after_count_distinct_matches() {
+        let mut backend = MockBackend {
+            updates: VecDeque::from([
+                Update { attempts: 100, matches: vec![found(1, 100)], ..Update::default() },
+                Update { attempts: 100, matches: vec![found(1, 50), found(2, 100)], ..Update::default() },
+                Update { attempts: 100, matches: vec![found(3, 100)], ..Update::default() },
+            ]),
+            ..MockBackend::default()
+        };
+        let recorder = Recorder::default();
+        let limits = Limits { count: Some(2), ..Limits::default() };
+        let summary = run(&mut backend, matcher(), job(limits, &recorder, no_checkpoint())).unwrap();
+
+        assert_eq!((summary.matches, summary.attempts), (2, 200));
+        assert!(backend.cancelled);
+        assert_eq!(backend.polls, 2);
+        assert_eq!(recorder.events("found"), vec![
+            format!("found ? {} 100", key(1).address()),
+            format!("found ? {} 200", key(2).address()),
+        ]);
+        assert_eq!(recorder.events("started"), vec!["started mock"]);
+        assert!(recorder.events("stopped").is_empty());
+    }
+
+    /// A match counts the keys of earlier polls and those the backend tried before it in this one
+    #[test]
+    fn attempts_until_match() {
+        let mut backend = MockBackend {
+            updates: VecDeque::from([
+                Update { attempts: 1000, ..Update::default() },
+                Update { attempts: 1000, matches: vec![found(1, 10), found(2, 1500)], ..Update::default() },
+            ]),
+            ..MockBackend::default()
+        };
+        let recorder = Recorder::default();
+        let limits = Limits { count: Some(2), ..Limits::default() };
+        let summary = run(&mut backend, matcher(), job(limits, &recorder, no_checkpoint())).unwrap();
+
+        assert_eq!(summary.attempts, 2000);
+        assert_eq!(recorder.events("found"), vec![
+            format!("found ? {} 1010", key(1).address()),
+            format!("found ? {} 2500", key(2).address()),
+        ]);
+    }
+
+    #[test]
+    fn stops_at_max_attempts() {
+        let mut backend = MockBackend { idle_attempts: 1000, ..MockBackend::default() };
+        let recorder = Recorder::default();
+        let limits = Limits { count: Some(1), max_attempts: Some(3500), ..Limits::default() };
+        let summary = run(&mut backend, matcher(), job(limits, &recorder, no_checkpoint())).unwrap();
+
+        assert_eq!((summary.matches, summary.attempts), (0, 4000));
+        assert!(backend.cancelled);
+        assert_eq!(recorder.events("progress"), vec!["progress 1000", "progress 2000", "progress 3000", "progress 4000"]);
+        assert_eq!(recorder.events("stopped"), vec!["stopped 0 4000"]);
+    }
+
+    #[test]
+    fn stops_when_cancelled() {
+        let cancel = Arc::new(std::sync::atomic::AtomicBool::new(true));
+        let mut backend = MockBackend { idle_attempts: 10, ..MockBackend::default() };
+        let recorder = Recorder::default();
+        let limits = Limits { cancel: Some(cancel), ..Limits::default() };
+        let summary = run(&mut backend, matcher(), job(limits, &recorder, no_checkpoint())).unwrap();
+
+        assert_eq!(summary.attempts, 10);
+        assert!(backend.cancelled);
+    }
+
+    #[test]
+    fn reports_faults() {
+        let mut backend = MockBackend {
+            updates: VecDeque::from([
+                Update { attempts: 1, faults: vec!["wrong key".to_string()], ..Update::default() },
+                Update { attempts: 1, faults: vec!["wrong address".to_string()], matches: vec![found(1, 1)], ..Update::default() },
+            ]),
+            ..MockBackend::default()
+        };
+        let recorder = Recorder::default();
+        let limits = Limits { count: Some(1), ..Limits::default() };
+        run(&mut backend, matcher(), job(limits, &recorder, no_checkpoint())).unwrap();
+
+        assert_eq!(recorder.events("fault"), vec!["fault 1 wrong key", "fault 2 wrong address"]);
+    }
+
+    #[test]
+    fn refuses_unsupported_patterns() {
+        let mut backend = MockBackend::default();
+        let recorder = Recorder::default();
+        let matcher = Matcher::new(vec![Pattern::parse("A").unwrap(), Pattern::parse("B").unwrap()]);
+        let result = run(&mut backend, matcher, job(Limits::default(), &recorder, no_checkpoint()));
+
+        assert_eq!(result.err().unwrap(), "The mock backend only supports a single pattern");
+        assert!(!backend.started);
+        assert!(recorder.events.borrow().is_empty());
+    }
+
+    #[test]
+    fn refuses_to_start_after_failed_self_test() {
+        let mut backend = MockBackend { self_test: Some("wrong public key".to_string()), ..MockBackend::default() };
+        let recorder = Recorder::default();
+        let result = run(&mut backend, matcher(), job(Limits::default(), &recorder, no_checkpoint()));
+
+        assert_eq!(result.err().unwrap(), "Self-test failed: wrong public key");
+        assert!(!backend.started);
+    }
+
+    #[test]
+    fn saves_and_resumes_checkpoints() {
+        let path = checkpoint_path("resume");
+        let mut backend = MockBackend {
+            updates: VecDeque::from([Update {
+                attempts: 500,
+                positions: vec![Worker { stream: 7, position: 500 }],
+                matches: vec![found(1, 200)],
+                ..Update::default()
+            }]),
+            idle_attempts: 500,
+            ..MockBackend::default()
+        };
+        let recorder = Recorder::default();
+        let limits = Limits { max_attempts: Some(1000), ..Limits::default() };
+        let checkpointer = Checkpointer::new(Some(path.clone()), Checkpoint::new(matcher().patterns()));
+        run(&mut backend, matcher(), job(limits, &recorder, checkpointer)).unwrap();
+
+        let saved = Checkpoint::load(&path).unwrap();
+        assert_eq!(saved.attempts, 1000);
+        assert_eq!(saved.matches, vec![key(1).address()]);
+        assert_eq!(saved.position(7), 500);
+
+        // The match found before the restart is neither reported again nor counted twice
+        let mut backend = MockBackend {
+            updates: VecDeque::from([Update { attempts: 100, matches: vec![found(1, 20), found(2, 100)], ..Update::default() }]),
+            ..MockBackend::default()
+        };
+        let recorder = Recorder::default();
+        let limits = Limits { count: Some(2), ..Limits::default() };
+        let summary = run(&mut backend, matcher(), job(limits, &recorder, Checkpointer::new(Some(path.clone()), saved))).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!((summary.matches, summary.attempts), (2, 1100));
+        assert_eq!(recorder.events("found"), vec![format!("found ? {} 1100", key(2).address())]);
+    }
+
+    #[test]
+    fn test_vector() {
+        assert_eq!(KeyPair::from_seed(TEST_SEED).address(), TEST_ADDRESS);
+    }
+}
@@ -0,0 +1,153 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+
+use crate::pattern::Pattern;
+
+/// How often a running search saves its state
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The state of a search, saved periodically so it can be resumed after a restart
+///
+/// Matches are stored as addresses and the key of the seed streams is left out,
+/// so the checkpoint can't be used to recreate any private key.
+/// The stream positions only mean something under the key they were reached with: a search resumed
+/// with the same session seed continues its streams, while one under a fresh random key starts them over
+/// with `restart_streams` and only carries over the attempts, time and matches.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// The patterns searched for, as written by `Pattern`'s `Display`
+    pub patterns: Vec<String>,
+    pub workers: Vec<Worker>,
+    pub attempts: u64,
+    pub elapsed_seconds: f64,
+    /// Addresses of the matches found so far
+    pub matches: Vec<String>,
+}
+
+/// Position of one seed stream
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub struct Worker {
+    pub stream: u64,
+    /// Number of seeds tested so far
+    pub position: u64,
+}
+
+impl Checkpoint {
+    pub fn new(patterns: &[Pattern]) -> Checkpoint {
+        Checkpoint {
+            patterns: patterns.iter().map(Pattern::to_string).collect(),
+            ..Checkpoint::default()
+        }
+    }
+
+    pub fn load(path: &Path) -> io::Result<Checkpoint> {
+        Ok(serde_json::from_slice(&fs::read(path)?)?)
+    }
+
+    /// Replaces the file at `path`, which is only ever readable by its owner
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut temporary = path.as_os_str().to_owned();
+        temporary.push(".tmp");
+        // a leftover temporary file would keep its permissions, so the mode only applies to a new one
+        match fs::remove_file(&temporary) {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+            _ => {}
+        }
+        let mut options = OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options.open(&temporary)?;
+        file.write_all(&serde_json::to_vec_pretty(self)?)?;
+        file.sync_all()?;
+        fs::rename(&temporary, path)
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_secs_f64(self.elapsed_seconds)
+    }
+
+    /// Forgets the stream positions, for a search resumed under a different key
+    pub fn restart_streams(&mut self) {
+        self.workers.clear();
+    }
+
+    /// Where the given stream stopped, or 0 if it is new
+    pub fn position(&self, stream: u64) -> u64 {
+        self.workers.iter().find(|worker| worker.stream == stream).map_or(0, |worker| worker.position)
+    }
+}
+
+/// Holds the state a search started from and saves its progress every `CHECKPOINT_INTERVAL`,
+/// if it was given a file
+pub struct Checkpointer {
+    path: Option<PathBuf>,
+    last_save: Instant,
+    initial: Checkpoint,
+}
+
+impl Checkpointer {
+    pub fn new(path: Option<PathBuf>, initial: Checkpoint) -> Checkpointer {
+        Checkpointer { path, last_save: Instant::now(), initial }
+    }
+
+    pub fn initial(&self) -> &Checkpoint {
+        &self.initial
+    }
+
+    /// Saves the state built by `state` if a save is due, or right away if `force` is set
+    pub fn update(&mut self, force: bool, state: impl FnOnce() -> Checkpoint) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        if !force && self.last_save.elapsed() < CHECKPOINT_INTERVAL {
+            return Ok(());
+        }
+        self.last_save = Instant::now();
+        state().save(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restart_streams() {
+        let mut checkpoint = Checkpoint {
+            workers: vec![Worker { stream: 3, position: 1 << 20 }],
+            attempts: 1 << 20,
+            matches: vec!["ALGO".to_string()],
+            ..Checkpoint::default()
+        };
+        assert_eq!(checkpoint.position(3), 1 << 20);
+        checkpoint.restart_streams();
+        assert_eq!(checkpoint.position(3), 0);
+        assert_eq!(checkpoint.attempts, 1 << 20);
+        assert_eq!(checkpoint.matches.len(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn save_replaces_stale_temporary_file() {
+        use std::os::unix::fs::PermissionsExt;
+        let directory = std::env::temp_dir().join(format!("algorand-vanity-checkpoint-{}", std::process::id()));
+        fs::create_dir_all(&directory).unwrap();
+        let path = directory.join("checkpoint.json");
+        let temporary = directory.join("checkpoint.json.tmp");
+        fs::write(&temporary, "stale").unwrap();
+        fs::set_permissions(&temporary, fs::Permissions::from_mode(0o644)).unwrap();
+
+        let checkpoint = Checkpoint { attempts: 42, ..Checkpoint::default() };
+        checkpoint.save(&path).unwrap();
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        let saved = Checkpoint::load(&path).unwrap();
+        fs::remove_dir_all(&directory).unwrap();
+        assert_eq!(mode & 0o777, 0o600);
+        assert_eq!(saved.attempts, 42);
+        assert!(!temporary.exists());
+    }
+}
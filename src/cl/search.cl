@@ -0,0 +1,112 @@
+// Searches for vanity addresses, assembled after sha512.cl and ed25519.cl
+
+static void ed25519_create_pubkey(
+                            unsigned char *public_key,
+                            const unsigned char *seed) {
+  ge25519 ALIGN(16) A;
+  // the hash pads its input in place, so the seed is copied into a whole block
+  unsigned long message[16];
+  unsigned long hash_words[8];
+  unsigned char *hash = (unsigned char *) hash_words;
+  bignum256modm a;
+
+  for (int j = 0; j < 32; j++) {
+    ((unsigned char *) message)[j] = seed[j];
+  }
+  hash_private(message, 32, hash_words);
+
+  hash[0] &= 248;
+  hash[31] &= 63;
+  hash[31] |= 64;
+  expand256_modm(a, hash, 32);
+  ge25519_scalarmult_base_niels(&A, a);
+  ge25519_pack(public_key, &A);
+}
+
+// Advance the seed to the next candidate, its last 8 bytes are a little-endian counter
+static void next_seed(unsigned char *seed) {
+  for (int j = 24; j < 32; j++) {
+    if (++seed[j] != 0) {
+      return;
+    }
+  }
+}
+
+// load 5 bytes from the char array into the 40 bit integer in reverse order
+// This way the first byte becomes the most significant byte and the mask can be applied properly
+static unsigned long load_5_bytes_reverse(const unsigned char *x) {
+  unsigned long r = 0;
+  r |= (unsigned long)x[0] << 32;
+  r |= (unsigned long)x[1] << 24;
+  r |= (unsigned long)x[2] << 16;
+  r |= (unsigned long)x[3] << 8;
+  r |= (unsigned long)x[4];
+  return r;
+}
+
+__constant unsigned char BASE32_LUT[] = "ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+// encode the lest significant 5 bits of x into an adequate base32 character
+static unsigned char b32_encode_bits(unsigned long x) {
+  return BASE32_LUT[x & 0x1f];
+}
+
+// encode public key into a base32 string and check if it matches the prefix
+static bool b32_compare(
+    __global const unsigned char *prefix,
+    const unsigned int prefix_len,
+    unsigned char *public_key
+) {
+	unsigned int consumed = 0;
+	for (int byte_index = 0; ; byte_index+=5) {
+		unsigned long current = load_5_bytes_reverse(&public_key[byte_index]);
+		unsigned long mask = 0x1fUL << 35;
+
+		// The first byte is the most significant in the 40 bit integer
+		for (int i = 7; i >= 0; i--) { // 8 iterations => 40 bits processed => 5 bytes processed
+			unsigned long val_to_encode = (current & mask) >> (i * 5);
+			unsigned char encoded = b32_encode_bits(val_to_encode);
+			if (encoded != prefix[consumed]) {
+				return false;
+			}
+			consumed++;
+			if (consumed >= prefix_len) {
+				return true;
+			}
+			mask >>= 5;
+		}
+	}
+}
+
+// Every work item tests `iterations` consecutive seeds starting from its own seed.
+// On a match the flag in `found` is set and the matching seed and public key are written back
+__kernel void brute_force_b32_prefix(
+    __global unsigned char *found,
+    __global unsigned char *public_key,
+    __global unsigned char *seed,
+    __global const unsigned char *prefix,
+    const unsigned int prefix_len,
+    const unsigned int iterations
+) {
+	unsigned id = get_global_id(0);
+	unsigned char seed_local[32];
+	// b32_compare loads 5 bytes at a time, so the key is padded to a multiple of 5
+	unsigned char public_key_local[35] = {0};
+	for (unsigned j = 0; j < 32; j++) {
+		seed_local[j] = seed[id * 32 + j];
+	}
+	for (unsigned i = 0; i < iterations; i++) {
+		ed25519_create_pubkey(public_key_local, seed_local);
+		bool result = b32_compare(prefix, prefix_len, public_key_local);
+		if (result) {
+			found[id] = true;
+			// copy key and seed over to globals
+			for (unsigned j = 0; j < 32; j++) {
+				public_key[id * 32 + j] = public_key_local[j];
+				seed[id * 32 + j] = seed_local[j];
+			}
+			return;
+		}
+		next_seed(seed_local);
+	}
+}
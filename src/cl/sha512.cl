@@ -43,7 +43,7 @@ typedef struct {
 
 #define rotl64(a,n) (rotate ((a), (n)))
 #define rotr64(a,n) (rotate ((a), (64ul-n)))
-unsigned long SWAP (const unsigned long val)
+static unsigned long SWAP (const unsigned long val)
 {
     // ab cd ef gh -> gh ef cd ab using the 32 bit trick
     unsigned long tmp = (rotr64(val & 0x0000FFFF0000FFFFUL, 16UL) | rotl64(val & 0xFFFF0000FFFF0000UL, 16UL));
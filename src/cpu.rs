@@ -1,119 +1,152 @@
-use crate::xoshiro256::Xoshiro256;
-use std::sync::mpsc;
-use crate::mnemonic;
-use std::time::Instant;
+use crate::seeds::{SeedStream, Streams};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::time::Duration;
+use crate::backend::{self, Found, SearchBackend, Update, TEST_ADDRESS, TEST_SEED};
+use crate::checkpoint::{Checkpoint, Worker};
 use crate::crypto::KeyPair;
-use std::io::stdout;
-use std::io::Write;
+use crate::matcher::Matcher;
+use crate::pattern::Pattern;
+use crate::search::{Job, Summary};
+use std::sync::Arc;
 
-/// Used for hashrate calculations
+/// Number of keys a thread tries between progress reports
 const HASH_MULTIPLIER: u64 = 1 << 14; // roughly 16k hashes
 const HASH_MULTIPLIER_MASK: u64 = HASH_MULTIPLIER - 1;
-/// How many seconds to remember for hashrate calculations
-const REMEMBER_SECONDS: usize = 8;
-
-pub fn convert(data: &[u64; 4]) -> [u8; 32] {
-    unsafe { std::mem::transmute(*data) }
-}
 
 struct ThreadResult {
-    pub address: String,
-    pub mnemonic: String,
+    pub thread: usize,
+    /// Position of the thread's stream after the match
+    pub position: u64,
+    pub pattern: usize,
+    pub kp: KeyPair,
 }
 
-struct CircularBuffer {
-    buffer: Vec<u64>,
-    index: usize,
+/// Searches on CPU threads, thread `i` taking its seeds from stream `i` of the job
+pub struct CpuBackend {
+    threads: usize,
+    /// Tells the threads to stop
+    stop: Arc<AtomicBool>,
+    streams: Option<Streams>,
+    positions: Vec<u64>,
+    results: Option<mpsc::Receiver<ThreadResult>>,
+    progress: Option<mpsc::Receiver<usize>>,
 }
 
-/// Used to measure the current key rate
-impl CircularBuffer {
-    pub fn new(size: usize) -> CircularBuffer {
-        CircularBuffer {
-            buffer: vec![0; size],
-            index: 0,
+impl CpuBackend {
+    pub fn new(threads: usize) -> CpuBackend {
+        CpuBackend {
+            threads,
+            stop: Arc::new(AtomicBool::new(false)),
+            streams: None,
+            positions: Vec::new(),
+            results: None,
+            progress: None,
         }
     }
+}
 
-    pub fn push(&mut self, value: u64) {
-        self.buffer[self.index] = value;
-        self.index = (self.index + 1) % self.buffer.len();
+impl SearchBackend for CpuBackend {
+    fn device(&self) -> String {
+        format!("{} CPU thread{}", self.threads, if self.threads == 1 { "" } else { "s" })
     }
 
-    pub fn sum(&self) -> u64 {
-        self.buffer.iter().sum()
+    fn supports(&self, _patterns: &[Pattern]) -> Result<(), String> {
+        Ok(())
     }
-}
-
-pub fn run(prefix: String, threads: usize) {
-    let mut main_rng = Xoshiro256::from_entropy();
 
-    let (tx, rx) = mpsc::channel();
-    let (progress_sender, progress_receiver) = mpsc::channel();
-    for _ in 0..threads {
-        main_rng.jump();
-        let prefix = prefix.clone();
-        let initial_seed = [main_rng.next(), main_rng.next(), main_rng.next(), main_rng.next()];
-        let tx = tx.clone();
-        let progress_sender = progress_sender.clone();
-        std::thread::spawn(move || {
-            run_cpu_thread(prefix, initial_seed, tx, progress_sender)
-        });
+    fn self_test(&mut self) -> Result<(), String> {
+        let kp = KeyPair::from_seed(TEST_SEED);
+        if kp.address() != TEST_ADDRESS {
+            return Err(format!("derived the address {} instead of {}", kp.address(), TEST_ADDRESS));
+        }
+        let matcher = Matcher::new(vec![Pattern::prefix(&TEST_ADDRESS[..4]).unwrap()]);
+        match matcher.find(&kp) {
+            Some(_) => Ok(()),
+            None => Err(format!("the prefix {} did not match {}", &TEST_ADDRESS[..4], TEST_ADDRESS)),
+        }
     }
-    let mut recent_key_count = CircularBuffer::new(REMEMBER_SECONDS);
-    let start = Instant::now();
-    let mut last = start;
-    println!("Search started!");
-    loop {
-        // Calculate the current hashrate
-        let mut hash_count = 0;
-        for _ in progress_receiver.try_iter() {
-            hash_count += HASH_MULTIPLIER;
+
+    fn start(&mut self, matcher: Arc<Matcher>, streams: Streams, resume: &Checkpoint) -> Result<(), String> {
+        let (tx, rx) = mpsc::channel();
+        let (progress_sender, progress_receiver) = mpsc::channel();
+        for thread in 0..self.threads {
+            let matcher = matcher.clone();
+            self.positions.push(resume.position(streams.number(thread)));
+            let seed_stream = streams.open(thread, self.positions[thread]);
+            let tx = tx.clone();
+            let progress_sender = progress_sender.clone();
+            let stop = self.stop.clone();
+            std::thread::spawn(move || {
+                run_cpu_thread(matcher, thread, seed_stream, tx, progress_sender, stop)
+            });
         }
-        recent_key_count.push(hash_count);
-        let total_recent_keys = recent_key_count.sum() as f64;
-        let now = Instant::now();
-        let time_elapsed = now.duration_since(last).as_millis() as f64;
-        last = now;
-        // display current hashrate
-        let mut key_rate = total_recent_keys / time_elapsed / REMEMBER_SECONDS as f64;
-        if key_rate.is_nan() {
-            key_rate = 0.0;
+        self.streams = Some(streams);
+        self.results = Some(rx);
+        self.progress = Some(progress_receiver);
+        Ok(())
+    }
+
+    fn poll(&mut self, timeout: Duration) -> Result<Update, String> {
+        let (Some(streams), Some(results), Some(progress)) = (&self.streams, &self.results, &self.progress) else {
+            return Err("The CPU search has not been started".to_string());
+        };
+        let mut update = Update::default();
+        let mut found = Vec::new();
+        match results.recv_timeout(timeout) {
+            Ok(result) => found.push(result),
+            Err(RecvTimeoutError::Timeout) => {}
+            Err(RecvTimeoutError::Disconnected) => return Err("The CPU threads have stopped".to_string()),
         }
-        print!("\rAvg. key search rate: {:.3}KK/s", key_rate);
-        stdout().flush().unwrap();
-        // check if the result is in
-        if let Ok(result) = rx.try_recv() {
-            println!();
-            println!("Match found!");
-            println!("Address: {}", result.address);
-            println!("Mnemonic: {}", result.mnemonic);
-            println!("Time: {}s", now.duration_since(start).as_secs());
-            return;
+        found.extend(results.try_iter());
+        for thread in progress.try_iter() {
+            update.attempts += HASH_MULTIPLIER;
+            self.positions[thread] += HASH_MULTIPLIER;
         }
-        // sleep for one second
-        std::thread::sleep(std::time::Duration::from_secs(1));
+        // the keys a thread tried since its last progress report aren't counted yet
+        update.matches = found.into_iter().map(|result| Found {
+            attempts: update.attempts + result.position.saturating_sub(self.positions[result.thread]),
+            pattern: result.pattern,
+            key_pair: result.kp,
+        }).collect();
+        update.positions = self.positions.iter().enumerate()
+            .map(|(thread, &position)| Worker { stream: streams.number(thread), position })
+            .collect();
+        Ok(update)
+    }
+
+    fn cancel(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for CpuBackend {
+    fn drop(&mut self) {
+        self.cancel();
     }
 }
 
-/// Search for a matching address in a single thread
-/// Each thread must receive a different seed
-fn run_cpu_thread(prefix: String, mut seed: [u64; 4], tx: mpsc::Sender<ThreadResult>, progeress_sender: mpsc::Sender<()>) {
+/// Searches on `threads` threads until one of the limits of the `job` is reached
+/// Thread `i` takes its seeds from stream `i` of the job
+pub fn run(matcher: Matcher, threads: usize, job: Job) -> Result<Summary, String> {
+    backend::run(&mut CpuBackend::new(threads), matcher, job)
+}
+
+/// Search for matching addresses in a single thread, reporting every match found
+/// Each thread must receive a different seed stream
+/// Returns once `stop` is set or nobody listens anymore
+fn run_cpu_thread(matcher: Arc<Matcher>, thread: usize, mut seed_stream: SeedStream, tx: mpsc::Sender<ThreadResult>, progeress_sender: mpsc::Sender<usize>, stop: Arc<AtomicBool>) {
     let mut hashes = 0u64;
     loop {
         hashes += 1;
-        if (hashes & HASH_MULTIPLIER_MASK) == 0 {
-            progeress_sender.send(()).unwrap();
-        }
-        seed[3] = seed[3].wrapping_add(1);
-
-        let kp = KeyPair::from_seed(convert(&seed));
-        if kp.check_prefix(&prefix) {
-            tx.send(ThreadResult {
-                address: kp.address(),
-                mnemonic: mnemonic::from_key(&kp.secret_key),
-            }).unwrap();
+        if (hashes & HASH_MULTIPLIER_MASK) == 0 && (stop.load(Ordering::Relaxed) || progeress_sender.send(thread).is_err()) {
             return;
         }
+        let kp = KeyPair::from_seed(seed_stream.next_seed());
+        if let Some(pattern) = matcher.find(&kp) {
+            if tx.send(ThreadResult { thread, position: seed_stream.position(), pattern, kp }).is_err() {
+                return;
+            }
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,13 +1,100 @@
-
+use std::fmt;
 use ed25519_dalek::{SecretKey, PublicKey};
 use sha2::Digest;
 
 
 const CHECKSUM_LEN: usize = 32;
-const PUBLIC_KEY_LEN: usize = 32;
-#[allow(dead_code)]
-const SEED_LEN: usize = 32;
+pub const PUBLIC_KEY_LEN: usize = 32;
+pub const SEED_LEN: usize = 32;
+/// Only the last 4 bytes of the checksum are appended to the public key
+pub const ADDRESS_CHECKSUM_LEN: usize = 4;
+/// Number of leading address characters which depend on the public key alone
+pub const PUBLIC_KEY_CHARS: usize = PUBLIC_KEY_LEN * 8 / 5;
+/// Length of a base32 encoded address
+pub const ADDRESS_LEN: usize = ((PUBLIC_KEY_LEN + ADDRESS_CHECKSUM_LEN) * 8).div_ceil(5);
+/// The last character of an address only carries 3 bits, so it can only be one of these
+pub const LAST_CHARS: &str = "AEIMQUY4";
+
+const ALPHABET: base32::Alphabet = base32::Alphabet::RFC4648 { padding: false };
+pub const ALPHABET_CHARS: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// Formats bytes as lowercase hex
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Parses hex digits in either case back into bytes, the inverse of `hex`
+pub fn from_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return None;
+    }
+    text.as_bytes().chunks_exact(2)
+        .map(|digits| u8::from_str_radix(std::str::from_utf8(digits).ok()?, 16).ok())
+        .collect()
+}
+
+/// Returns the 5 bit value of a base32 character
+pub fn symbol_value(c: u8) -> Option<u8> {
+    ALPHABET_CHARS.iter().position(|&a| a == c).map(|v| v as u8)
+}
+
+/// Returns the 5 bit value of the base32 character at the given index of the encoded bytes
+pub fn symbol_at(bytes: &[u8], index: usize) -> u8 {
+    let bit = index * 5;
+    let hi = bytes[bit / 8] as u16;
+    let lo = *bytes.get(bit / 8 + 1).unwrap_or(&0) as u16;
+    ((((hi << 8) | lo) >> (11 - bit % 8)) & 0x1f) as u8
+}
+
+
+#[derive(Debug, PartialEq)]
+pub enum AddressError {
+    WrongLength { chars: usize },
+    /// A character outside the base32 alphabet, or a last character which sets unused bits
+    InvalidCharacter,
+    BadChecksum,
+}
 
+impl fmt::Display for AddressError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AddressError::WrongLength { chars } => write!(f, "Address has {} characters, but must have {}", chars, ADDRESS_LEN),
+            AddressError::InvalidCharacter => write!(f, "Address is not valid base32"),
+            AddressError::BadChecksum => write!(f, "Address checksum does not match, a character is mistyped"),
+        }
+    }
+}
+
+fn address_bytes(public_key: &[u8; PUBLIC_KEY_LEN]) -> [u8; PUBLIC_KEY_LEN + ADDRESS_CHECKSUM_LEN] {
+    let mut address = [0u8; PUBLIC_KEY_LEN + ADDRESS_CHECKSUM_LEN];
+    address[..PUBLIC_KEY_LEN].copy_from_slice(public_key);
+    let checksum = sha2::Sha512_256::digest(public_key);
+    address[PUBLIC_KEY_LEN..].copy_from_slice(&checksum[CHECKSUM_LEN - ADDRESS_CHECKSUM_LEN..CHECKSUM_LEN]);
+    address
+}
+
+/// The address of a public key: the key and its checksum in base32
+pub fn encode_address(public_key: &[u8; PUBLIC_KEY_LEN]) -> String {
+    base32::encode(ALPHABET, &address_bytes(public_key))
+}
+
+/// The public key of an address, the inverse of `encode_address`, verifying its checksum
+/// Case is ignored
+pub fn decode_address(address: &str) -> Result<[u8; PUBLIC_KEY_LEN], AddressError> {
+    let address = address.to_ascii_uppercase();
+    if address.len() != ADDRESS_LEN {
+        return Err(AddressError::WrongLength { chars: address.chars().count() });
+    }
+    let bytes = base32::decode(ALPHABET, &address).ok_or(AddressError::InvalidCharacter)?;
+    let public_key: [u8; PUBLIC_KEY_LEN] = bytes.get(..PUBLIC_KEY_LEN)
+        .and_then(|key| key.try_into().ok())
+        .ok_or(AddressError::InvalidCharacter)?;
+    // Rejects a last character which doesn't round trip, as it would set bits past the checksum
+    if encode_address(&public_key) != address {
+        return Err(if address.ends_with(|c| LAST_CHARS.contains(c)) { AddressError::BadChecksum } else { AddressError::InvalidCharacter });
+    }
+    Ok(public_key)
+}
 
 #[derive(Copy,Clone)]
 pub struct KeyPair {
@@ -16,7 +103,7 @@ pub struct KeyPair {
 }
 
 impl KeyPair {
-    pub fn from_seed(seed: [u8; 32]) -> KeyPair {
+    pub fn from_seed(seed: [u8; SEED_LEN]) -> KeyPair {
         let secret_key = SecretKey::from_bytes(&seed).unwrap();
         let public_key = PublicKey::from(&secret_key);
         KeyPair {
@@ -25,18 +112,12 @@ impl KeyPair {
         }
     }
 
-    /// Works similarily to an address, but skips calculating the checksum
-    /// Returns true if the address starts with the given prefix
-    pub fn check_prefix(&self, prefix: &str) -> bool {
-        let encoded = base32::encode(base32::Alphabet::RFC4648 { padding: false }, &self.public_key);
-        encoded.starts_with(prefix)
+    /// The public key followed by the last 4 bytes of its checksum
+    pub fn address_bytes(&self) -> [u8; PUBLIC_KEY_LEN + ADDRESS_CHECKSUM_LEN] {
+        address_bytes(&self.public_key)
     }
 
     pub fn address(&self) -> String {
-        let mut address: Vec<u8> = Vec::with_capacity(CHECKSUM_LEN + PUBLIC_KEY_LEN);
-        address.extend(self.public_key);
-        let checksum = sha2::Sha512_256::digest(&address)[28..32].to_vec();
-        address.extend(checksum);
-        base32::encode(base32::Alphabet::RFC4648 { padding: false }, &address)
+        encode_address(&self.public_key)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,161 @@
+use crate::crypto::ADDRESS_LEN;
+use crate::pattern::Pattern;
+
+/// Number of values the last address character can take
+const LAST_CHAR_VALUES: f64 = 8.0;
+/// Chances of having found a match for which the required attempts are displayed
+pub const CONFIDENCE_LEVELS: [f64; 3] = [0.5, 0.9, 0.99];
+
+/// Models how hard it is to find an address matching any of the patterns
+/// Every key is treated as an independent trial, so the number of attempts follows a geometric distribution
+pub struct Difficulty {
+    /// Probability that a random address matches
+    probability: f64,
+}
+
+impl Difficulty {
+    pub fn of(patterns: &[Pattern]) -> Difficulty {
+        // Treats the patterns as independent, which slightly overestimates overlapping sets
+        // Works with logarithms to keep tiny probabilities from rounding to zero
+        let log_miss: f64 = patterns.iter().map(|p| (-pattern_probability(p)).ln_1p()).sum();
+        Difficulty { probability: -log_miss.exp_m1() }
+    }
+
+    pub fn probability(&self) -> f64 {
+        self.probability
+    }
+
+    pub fn expected_attempts(&self) -> f64 {
+        1.0 / self.probability
+    }
+
+    /// Number of attempts after which a match has been found with the given probability
+    pub fn attempts_for(&self, confidence: f64) -> f64 {
+        (-confidence).ln_1p() / (-self.probability).ln_1p()
+    }
+
+    /// Probability that a match has been found within the given number of attempts
+    pub fn chance_after(&self, attempts: f64) -> f64 {
+        -(attempts * (-self.probability).ln_1p()).exp_m1()
+    }
+
+    /// Describes the chances of a search which made `attempts` so far, for the progress line
+    pub fn progress(&self, attempts: u64, keys_per_second: f64) -> String {
+        let remaining: Vec<String> = CONFIDENCE_LEVELS.iter()
+            .map(|&q| format_duration((self.attempts_for(q) - attempts as f64).max(0.0) / keys_per_second))
+            .collect();
+        format!(
+            "Chance of a match by now: {:.1}% | ETA: {} | 50%/90%/99%: {}",
+            self.chance_after(attempts as f64) * 100.0,
+            format_duration(self.expected_attempts() / keys_per_second),
+            remaining.join(" / "),
+        )
+    }
+}
+
+fn pattern_probability(pattern: &Pattern) -> f64 {
+    pattern.classes().iter().enumerate().map(|(i, class)| {
+        if pattern.start() + i == ADDRESS_LEN - 1 {
+            class.count_ones() as f64 / LAST_CHAR_VALUES
+        } else {
+            class.count_ones() as f64 / 32.0
+        }
+    }).product()
+}
+
+/// Formats a number of seconds using the two most significant units
+pub fn format_duration(seconds: f64) -> String {
+    const UNITS: [(&str, f64); 5] = [("y", 365.25 * 86400.0), ("d", 86400.0), ("h", 3600.0), ("m", 60.0), ("s", 1.0)];
+    if !seconds.is_finite() {
+        return "unknown".to_string();
+    }
+    let mut remaining = seconds.max(0.0).round();
+    let mut parts = Vec::new();
+    for (unit, length) in UNITS {
+        if remaining >= length || (parts.is_empty() && unit == "s") {
+            let count = (remaining / length).floor();
+            remaining -= count * length;
+            if count >= 1e6 {
+                parts.push(format!("{:.2e}{}", count, unit));
+                break;
+            } else {
+                parts.push(format!("{}{}", count, unit));
+            }
+        } else if !parts.is_empty() {
+            break;
+        }
+        if parts.len() == 2 {
+            break;
+        }
+    }
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn of(patterns: &[&str]) -> Difficulty {
+        let patterns: Vec<Pattern> = patterns.iter().map(|text| Pattern::parse(text).unwrap()).collect();
+        Difficulty::of(&patterns)
+    }
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() <= expected.abs() * 1e-9, "{} is not {}", actual, expected);
+    }
+
+    #[test]
+    fn single_prefix() {
+        let difficulty = of(&["ABC"]);
+        assert_close(difficulty.probability(), 1.0 / 32768.0);
+        assert_close(difficulty.expected_attempts(), 32768.0);
+        assert_close(of(&["[AB]C"]).probability(), 2.0 / 1024.0);
+    }
+
+    #[test]
+    fn last_character() {
+        // the last character only carries 3 bits, so it takes one of 8 values
+        assert_close(of(&["A$"]).probability(), 1.0 / 8.0);
+        assert_close(of(&["BA$"]).probability(), 1.0 / 32.0 / 8.0);
+        assert_close(of(&["[AE]$"]).probability(), 2.0 / 8.0);
+        assert_close(of(&["A$"]).probability(), of(&["@57:A"]).probability());
+    }
+
+    #[test]
+    fn several_patterns() {
+        assert_close(of(&["A", "B"]).probability(), 1.0 - (31.0 / 32.0) * (31.0 / 32.0));
+        // rare patterns add up
+        let single = of(&["ABCD"]).probability();
+        let combined = of(&["ABCD", "EFGH", "IJQ$"]).probability();
+        assert!((combined - (2.0 * single + 1.0 / 32.0 / 32.0 / 8.0)).abs() < combined * 1e-3);
+    }
+
+    #[test]
+    fn attempts_and_chance() {
+        let difficulty = of(&["ABCD"]);
+        for confidence in [0.5, 0.9] {
+            assert_close(difficulty.chance_after(difficulty.attempts_for(confidence)), confidence);
+        }
+        // the median of a geometric distribution is about ln 2 times its mean
+        assert!((difficulty.attempts_for(0.5) / difficulty.expected_attempts() - 2f64.ln()).abs() < 1e-4);
+        assert_eq!(difficulty.chance_after(0.0), 0.0);
+    }
+
+    #[test]
+    fn durations() {
+        assert_eq!(format_duration(0.0), "0s");
+        assert_eq!(format_duration(-5.0), "0s");
+        assert_eq!(format_duration(59.4), "59s");
+        assert_eq!(format_duration(60.0), "1m");
+        assert_eq!(format_duration(61.0), "1m 1s");
+        assert_eq!(format_duration(3599.0), "59m 59s");
+        assert_eq!(format_duration(3600.0), "1h");
+        assert_eq!(format_duration(3661.0), "1h 1m");
+        assert_eq!(format_duration(86399.0), "23h 59m");
+        assert_eq!(format_duration(86400.0), "1d");
+        assert_eq!(format_duration(365.25 * 86400.0), "1y");
+        assert_eq!(format_duration(400.0 * 86400.0), "1y 34d");
+        assert_eq!(format_duration(1e7 * 365.25 * 86400.0), "1.00e7y");
+        assert_eq!(format_duration(f64::INFINITY), "unknown");
+    }
+}
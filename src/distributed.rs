@@ -0,0 +1,327 @@
+use std::collections::HashSet;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc, Mutex};
+use std::time::{Duration, Instant};
+use serde::{Deserialize, Serialize};
+
+use crate::checkpoint::{Checkpoint, Checkpointer};
+use crate::crypto::{from_hex, hex, KeyPair};
+use crate::difficulty::Difficulty;
+use crate::matcher::Matcher;
+use crate::mnemonic;
+use crate::pattern::Pattern;
+use crate::search::{Job, Limits, Reporter, Summary};
+use crate::seeds::{SeedStream, Streams};
+/// How often the coordinator shows the combined progress, and workers report theirs
+const REPORT_INTERVAL: Duration = Duration::from_secs(1);
+/// How long a new connection has to present the token
+const JOIN_TIMEOUT: Duration = Duration::from_secs(10);
+/// How often the coordinator checks for new workers, and whether it should stop accepting them
+const ACCEPT_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Sent as one JSON object per line in both directions
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Message {
+    /// First message of a worker, only answered if the token is the one given to the coordinator
+    Join { token: String },
+    /// Tells a new worker what to search for, with its own block of seed streams under the session key
+    Assign { patterns: Vec<String>, key: String, first_stream: u64 },
+    /// The search is over
+    Stop,
+    /// Attempts made by the worker so far, and its current key rate
+    Progress { attempts: u64, keys_per_second: f64 },
+    /// A match, with the attempts made by the worker until it was found
+    Found { mnemonic: String, attempts: u64 },
+}
+
+fn send(stream: &mut TcpStream, message: &Message) -> std::io::Result<()> {
+    let mut line = serde_json::to_vec(message)?;
+    line.push(b'\n');
+    stream.write_all(&line)
+}
+
+fn messages(stream: TcpStream) -> impl Iterator<Item = Message> {
+    BufReader::new(stream).lines()
+        .map_while(Result::ok)
+        .map_while(|line| serde_json::from_str(&line).ok())
+}
+
+/// What the coordinator hears from the connections
+enum Event {
+    Joined { worker: usize, peer: String, writer: TcpStream },
+    Progress { worker: usize, attempts: u64, keys_per_second: f64 },
+    Found { worker: usize, mnemonic: String, attempts: u64 },
+    Left { worker: usize },
+    Refused { peer: String },
+}
+
+#[derive(Default)]
+struct WorkerState {
+    attempts: u64,
+    keys_per_second: f64,
+    writer: Option<TcpStream>,
+}
+
+/// Hands out the search to every worker connecting to `listener` and collects their matches
+/// until one of the `limits` is reached
+///
+/// All workers share a session key, each getting its own block of streams,
+/// so no two workers ever test the same seed. A worker which goes away simply stops contributing,
+/// its streams are never handed out again and the others carry on.
+/// Matches are only accepted after recomputing the key and checking it against the patterns.
+/// Connections which don't present `token` first are closed without learning anything about the search.
+/// The listener is closed once the search is over.
+pub fn coordinate(listener: TcpListener, token: &str, patterns: Vec<Pattern>, limits: Limits, console: &dyn Reporter) -> Result<Summary, String> {
+    listener.set_nonblocking(true).map_err(|err| format!("Failed to accept workers: {}", err))?;
+    let texts: Vec<String> = patterns.iter().map(Pattern::to_string).collect();
+    let key = SeedStream::random_key();
+    let (tx, rx) = mpsc::channel();
+    let token = token.to_string();
+    let stop = Arc::new(AtomicBool::new(false));
+    let accepting = {
+        let stop = stop.clone();
+        std::thread::spawn(move || accept_workers(listener, token, texts, key, tx, &stop))
+    };
+    let summary = collect(rx, Matcher::new(patterns), &limits, console);
+    stop.store(true, Ordering::Relaxed);
+    let _ = accepting.join();
+    Ok(summary)
+}
+
+/// Follows the workers until one of the `limits` is reached, then tells them to stop
+fn collect(rx: mpsc::Receiver<Event>, matcher: Matcher, limits: &Limits, console: &dyn Reporter) -> Summary {
+    let difficulty = Difficulty::of(matcher.patterns());
+    let mut workers: Vec<WorkerState> = Vec::new();
+    let mut found = HashSet::new();
+    let start = Instant::now();
+    let mut last_report = start;
+    console.started("workers");
+    loop {
+        let event = rx.recv_timeout(REPORT_INTERVAL);
+        let attempts = workers.iter().map(|worker| worker.attempts).sum();
+        let elapsed = start.elapsed();
+        match event {
+            Ok(Event::Joined { worker, peer, writer }) => {
+                workers.resize_with(workers.len().max(worker + 1), WorkerState::default);
+                workers[worker].writer = Some(writer);
+                console.info(&format!("Worker {} joined from {}", worker, peer));
+            }
+            Ok(Event::Progress { worker, attempts, keys_per_second }) => {
+                workers[worker].attempts = attempts;
+                workers[worker].keys_per_second = keys_per_second;
+            }
+            Ok(Event::Found { worker, mnemonic, attempts: until_match }) => {
+                let kp = mnemonic::to_key(&mnemonic).ok().map(KeyPair::from_seed);
+                match kp.and_then(|kp| Some((matcher.find(&kp)?, kp))) {
+                    Some((pattern, kp)) => {
+                        if found.insert(kp.address()) {
+                            // the other workers count as far as they have reported
+                            let attempts = attempts - workers[worker].attempts + until_match.max(workers[worker].attempts);
+                            console.found(pattern, matcher.pattern(pattern), &kp, attempts, elapsed);
+                        }
+                    }
+                    None => console.warning(&format!("Worker {} reported a key which does not match, ignoring it", worker)),
+                }
+                if limits.enough_matches(found.len()) {
+                    stop_workers(&mut workers);
+                    return Summary { matches: found.len(), attempts, elapsed };
+                }
+            }
+            Ok(Event::Left { worker }) => {
+                workers[worker].writer = None;
+                workers[worker].keys_per_second = 0.0;
+                console.info(&format!("Worker {} left", worker));
+            }
+            Ok(Event::Refused { peer }) => console.warning(&format!("Refused a connection from {} without the token", peer)),
+            Err(_) => {}
+        }
+        if limits.exhausted(attempts, elapsed) {
+            stop_workers(&mut workers);
+            let summary = Summary { matches: found.len(), attempts, elapsed };
+            console.stopped(&summary);
+            return summary;
+        }
+        if last_report.elapsed() >= REPORT_INTERVAL {
+            last_report = Instant::now();
+            let keys_per_second = workers.iter().map(|worker| worker.keys_per_second).sum();
+            console.progress(attempts, elapsed, keys_per_second, &difficulty);
+        }
+    }
+}
+
+/// Accepts workers on the non-blocking `listener` until `stop` is set
+fn accept_workers(listener: TcpListener, token: String, patterns: Vec<String>, key: [u8; 32], tx: mpsc::Sender<Event>, stop: &AtomicBool) {
+    let token = Arc::new(token);
+    let next_worker = Arc::new(AtomicUsize::new(0));
+    while !stop.load(Ordering::Relaxed) {
+        let stream = match listener.accept() {
+            // some platforms pass the non-blocking mode on to accepted connections
+            Ok((stream, _)) if stream.set_nonblocking(false).is_ok() => stream,
+            Ok(_) => continue,
+            Err(_) => {
+                std::thread::sleep(ACCEPT_INTERVAL);
+                continue;
+            }
+        };
+        let (token, patterns, next_worker, tx) = (token.clone(), patterns.clone(), next_worker.clone(), tx.clone());
+        std::thread::spawn(move || serve_worker(stream, &token, patterns, key, &next_worker, tx));
+    }
+}
+
+/// Checks the token of a new connection, then hands it the next block of streams and passes on what it reports
+fn serve_worker(mut stream: TcpStream, token: &str, patterns: Vec<String>, key: [u8; 32], next_worker: &AtomicUsize, tx: mpsc::Sender<Event>) {
+    let peer = stream.peer_addr().map_or_else(|_| "an unknown address".to_string(), |peer| peer.to_string());
+    let (Ok(writer), Ok(reader)) = (stream.try_clone(), stream.try_clone()) else {
+        return;
+    };
+    if stream.set_read_timeout(Some(JOIN_TIMEOUT)).is_err() {
+        return;
+    }
+    let mut incoming = messages(reader);
+    match incoming.next() {
+        Some(Message::Join { token: given }) if same_token(&given, token) => {}
+        _ => {
+            let _ = tx.send(Event::Refused { peer });
+            return;
+        }
+    }
+    if stream.set_read_timeout(None).is_err() {
+        return;
+    }
+    // only workers which joined use up a block
+    let worker = next_worker.fetch_add(1, Ordering::Relaxed);
+    let assign = Message::Assign {
+        patterns,
+        key: hex(&key),
+        first_stream: Streams::block(key, worker as u64).first,
+    };
+    // the coordinator knows the worker before it can report anything, a failed send ends in `Left`
+    if tx.send(Event::Joined { worker, peer, writer }).is_err() {
+        return;
+    }
+    let _ = send(&mut stream, &assign);
+    for message in incoming {
+        let event = match message {
+            Message::Progress { attempts, keys_per_second } => Event::Progress { worker, attempts, keys_per_second },
+            Message::Found { mnemonic, attempts } => Event::Found { worker, mnemonic, attempts },
+            _ => continue,
+        };
+        if tx.send(event).is_err() {
+            return;
+        }
+    }
+    let _ = tx.send(Event::Left { worker });
+}
+
+/// Compares the tokens in time independent of where they differ
+fn same_token(given: &str, token: &str) -> bool {
+    given.len() == token.len() && given.bytes().zip(token.bytes()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+}
+
+fn stop_workers(workers: &mut [WorkerState]) {
+    for writer in workers.iter_mut().filter_map(|worker| worker.writer.as_mut()) {
+        let _ = send(writer, &Message::Stop);
+    }
+}
+
+/// Passes the progress and matches of a worker on to the coordinator,
+/// and shows everything but the keys of matches on the console
+struct Remote<'a> {
+    writer: Mutex<TcpStream>,
+    last_progress: Mutex<Instant>,
+    /// Set once the connection is gone, which stops the search
+    cancel: Arc<AtomicBool>,
+    console: &'a dyn Reporter,
+}
+
+impl Remote<'_> {
+    fn send(&self, message: &Message) {
+        if send(&mut self.writer.lock().unwrap(), message).is_err() {
+            self.cancel.store(true, Ordering::Relaxed);
+        }
+    }
+}
+
+impl Reporter for Remote<'_> {
+    fn started(&self, device: &str) {
+        self.console.started(device);
+    }
+
+    fn progress(&self, attempts: u64, elapsed: Duration, keys_per_second: f64, difficulty: &Difficulty) {
+        self.console.progress(attempts, elapsed, keys_per_second, difficulty);
+        let mut last_progress = self.last_progress.lock().unwrap();
+        if last_progress.elapsed() >= REPORT_INTERVAL {
+            *last_progress = Instant::now();
+            self.send(&Message::Progress { attempts, keys_per_second });
+        }
+    }
+
+    fn found(&self, _index: usize, _pattern: &Pattern, kp: &KeyPair, attempts: u64, _elapsed: Duration) {
+        self.send(&Message::Found { mnemonic: mnemonic::from_key(&kp.secret_key), attempts });
+        self.console.info(&format!("Found {}, sent it to the coordinator", kp.address()));
+    }
+
+    fn kernel_fault(&self, number: usize, fault: &str) {
+        self.console.kernel_fault(number, fault);
+    }
+
+    fn warning(&self, message: &str) {
+        self.console.warning(message);
+    }
+
+    fn stopped(&self, summary: &Summary) {
+        self.console.stopped(summary);
+    }
+}
+
+/// Joins the coordinator on `stream` with `token` and searches with `search` until the coordinator stops it
+/// `search` runs one of the local backends on the assigned patterns
+pub fn work(mut stream: TcpStream, token: &str, console: &dyn Reporter, search: impl FnOnce(Vec<Pattern>, Job) -> Result<Summary, String>) -> Result<Summary, String> {
+    let lost_connection = |err: std::io::Error| format!("Lost the connection to the coordinator: {}", err);
+    send(&mut stream, &Message::Join { token: token.to_string() }).map_err(lost_connection)?;
+    let mut incoming = messages(stream.try_clone().map_err(lost_connection)?);
+    let (patterns, key, first_stream) = match incoming.next() {
+        Some(Message::Assign { patterns, key, first_stream }) => (patterns, key, first_stream),
+        _ => return Err("The coordinator did not assign any work, check the token".to_string()),
+    };
+    let patterns = patterns.iter()
+        .map(|text| Pattern::parse(text).map_err(|err| format!("The coordinator sent an invalid pattern {}: {}", text, err)))
+        .collect::<Result<Vec<Pattern>, String>>()?;
+    let key = from_hex(&key).and_then(|key| key.try_into().ok())
+        .ok_or_else(|| "The coordinator sent an invalid key".to_string())?;
+
+    let stopped = Arc::new(AtomicBool::new(false));
+    // Stops the search when the coordinator says so, or goes away
+    let cancel = Arc::new(AtomicBool::new(false));
+    {
+        let (stopped, cancel) = (stopped.clone(), cancel.clone());
+        std::thread::spawn(move || {
+            if incoming.any(|message| matches!(message, Message::Stop)) {
+                stopped.store(true, Ordering::Relaxed);
+            }
+            cancel.store(true, Ordering::Relaxed);
+        });
+    }
+    let remote = Remote {
+        writer: Mutex::new(stream),
+        last_progress: Mutex::new(Instant::now()),
+        cancel: cancel.clone(),
+        console,
+    };
+    let job = Job {
+        limits: Limits { cancel: Some(cancel), ..Limits::default() },
+        reporter: &remote,
+        streams: Streams { key, first: first_stream },
+        checkpointer: Checkpointer::new(None, Checkpoint::new(&patterns)),
+    };
+    let summary = search(patterns, job)?;
+    // Sends failing after the stop only mean the coordinator has already exited
+    if stopped.load(Ordering::Relaxed) {
+        Ok(summary)
+    } else {
+        Err("Lost the connection to the coordinator".to_string())
+    }
+}
@@ -0,0 +1,597 @@
+//! C ABI for other languages, declared in `include/algorand_vanity.h` and built with the `ffi` feature
+//!
+//! Keys and addresses are written to buffers owned by the caller. The only memory handed out is
+//! mnemonic strings, released with `av_string_free`, and searches, released with `av_search_free`.
+
+use std::ffi::{c_char, CStr, CString};
+use std::ptr;
+use std::sync::mpsc::RecvTimeoutError;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use crate::crypto::{self, KeyPair, ADDRESS_LEN, PUBLIC_KEY_LEN, SEED_LEN};
+use crate::mnemonic;
+use crate::pattern::Pattern;
+use crate::searcher::{Canceller, Search, Searcher};
+
+#[repr(C)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AvStatus {
+    Ok = 0,
+    /// A null pointer, a string which is not UTF-8, an unknown option or a search without patterns
+    InvalidArgument = 1,
+    InvalidMnemonic = 2,
+    InvalidAddress = 3,
+    InvalidPattern = 4,
+    /// `av_search_poll` found no match within its timeout
+    Timeout = 5,
+    /// The search has ended and every match has been polled
+    SearchEnded = 6,
+    /// The search stopped on an error after every match found before it has been polled,
+    /// `av_search_error` tells why
+    SearchFailed = 7,
+}
+
+/// Values of `AvSearchOptions::pattern_type`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AvPatternType {
+    Pattern = 0,
+    Prefix = 1,
+    Suffix = 2,
+}
+
+impl AvPatternType {
+    fn from_raw(value: u32) -> Option<AvPatternType> {
+        [AvPatternType::Pattern, AvPatternType::Prefix, AvPatternType::Suffix].into_iter()
+            .find(|pattern_type| *pattern_type as u32 == value)
+    }
+}
+
+#[repr(C)]
+pub struct AvSearchOptions {
+    pub patterns: *const *const c_char,
+    /// At least 1, a search without patterns could never find a match
+    pub pattern_count: usize,
+    /// One of `AvPatternType`, a plain integer as C may pass any value
+    pub pattern_type: u32,
+    /// 0 uses every logical CPU
+    pub threads: usize,
+    /// Distinct matches to find, 0 for no limit
+    pub count: usize,
+    /// 0 for no limit
+    pub max_seconds: f64,
+    /// 0 for no limit
+    pub max_attempts: u64,
+}
+
+#[repr(C)]
+pub struct AvMatch {
+    /// Index of the matched pattern in `AvSearchOptions::patterns`
+    pub pattern: usize,
+    pub secret_key: [u8; SEED_LEN],
+    pub public_key: [u8; PUBLIC_KEY_LEN],
+    /// NUL terminated
+    pub address: [c_char; ADDRESS_LEN + 1],
+    pub attempts: u64,
+    pub elapsed_seconds: f64,
+}
+
+/// A running search, opaque to C
+pub struct AvSearch {
+    /// `None` once the search has ended
+    search: Mutex<Option<Search>>,
+    /// Why the search failed, if it did
+    error: Mutex<Option<String>>,
+    canceller: Canceller,
+}
+
+unsafe fn to_str<'a>(text: *const c_char) -> Option<&'a str> {
+    if text.is_null() {
+        return None;
+    }
+    CStr::from_ptr(text).to_str().ok()
+}
+
+fn write_address(address: &str, out: &mut [c_char; ADDRESS_LEN + 1]) {
+    for (out, &byte) in out.iter_mut().zip(address.as_bytes()) {
+        *out = byte as c_char;
+    }
+    out[ADDRESS_LEN] = 0;
+}
+
+/// Derives the public key of a private key seed
+///
+/// # Safety
+/// `seed` must point to `SEED_LEN` readable bytes and `public_key` to `PUBLIC_KEY_LEN` writable ones.
+#[no_mangle]
+pub unsafe extern "C" fn av_public_key(seed: *const [u8; SEED_LEN], public_key: *mut [u8; PUBLIC_KEY_LEN]) -> AvStatus {
+    if seed.is_null() || public_key.is_null() {
+        return AvStatus::InvalidArgument;
+    }
+    *public_key = KeyPair::from_seed(*seed).public_key;
+    AvStatus::Ok
+}
+
+/// Writes the address of a public key as a NUL terminated string
+///
+/// # Safety
+/// `public_key` must point to `PUBLIC_KEY_LEN` readable bytes and `address` to `ADDRESS_LEN + 1` writable ones.
+#[no_mangle]
+pub unsafe extern "C" fn av_encode_address(public_key: *const [u8; PUBLIC_KEY_LEN], address: *mut [c_char; ADDRESS_LEN + 1]) -> AvStatus {
+    if public_key.is_null() || address.is_null() {
+        return AvStatus::InvalidArgument;
+    }
+    write_address(&crypto::encode_address(&*public_key), &mut *address);
+    AvStatus::Ok
+}
+
+/// Reads the public key of an address, verifying its checksum
+///
+/// # Safety
+/// `address` must be a NUL terminated string and `public_key` must point to `PUBLIC_KEY_LEN` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn av_decode_address(address: *const c_char, public_key: *mut [u8; PUBLIC_KEY_LEN]) -> AvStatus {
+    let Some(address) = to_str(address) else {
+        return AvStatus::InvalidArgument;
+    };
+    if public_key.is_null() {
+        return AvStatus::InvalidArgument;
+    }
+    match crypto::decode_address(address) {
+        Ok(key) => {
+            *public_key = key;
+            AvStatus::Ok
+        }
+        Err(_) => AvStatus::InvalidAddress,
+    }
+}
+
+/// Returns the 25 word mnemonic of a private key seed, to be released with `av_string_free`,
+/// or null if `key` is null
+///
+/// # Safety
+/// `key` must point to `SEED_LEN` readable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn av_mnemonic_from_key(key: *const [u8; SEED_LEN]) -> *mut c_char {
+    if key.is_null() {
+        return ptr::null_mut();
+    }
+    CString::new(mnemonic::from_key(&*key)).map_or(ptr::null_mut(), CString::into_raw)
+}
+
+/// Reads the private key seed of a 25 word mnemonic, verifying its checksum
+///
+/// # Safety
+/// `mnemonic` must be a NUL terminated string and `key` must point to `SEED_LEN` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn av_mnemonic_to_key(mnemonic: *const c_char, key: *mut [u8; SEED_LEN]) -> AvStatus {
+    let Some(mnemonic) = to_str(mnemonic) else {
+        return AvStatus::InvalidArgument;
+    };
+    if key.is_null() {
+        return AvStatus::InvalidArgument;
+    }
+    match mnemonic::to_key(mnemonic) {
+        Ok(seed) => {
+            *key = seed;
+            AvStatus::Ok
+        }
+        Err(_) => AvStatus::InvalidMnemonic,
+    }
+}
+
+/// Releases a string returned by this library, null is ignored
+///
+/// # Safety
+/// `text` must have been returned by this library and not been released before.
+#[no_mangle]
+pub unsafe extern "C" fn av_string_free(text: *mut c_char) {
+    if !text.is_null() {
+        drop(CString::from_raw(text));
+    }
+}
+
+/// Starts a search on CPU threads and stores its handle in `search`
+/// Returns `AV_INVALID_ARGUMENT` if `options` has no patterns
+///
+/// # Safety
+/// `options` must be valid, with `pattern_count` NUL terminated strings in `patterns`,
+/// and `search` must point to a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn av_search_start(options: *const AvSearchOptions, search: *mut *mut AvSearch) -> AvStatus {
+    if options.is_null() || search.is_null() {
+        return AvStatus::InvalidArgument;
+    }
+    let options = &*options;
+    if options.patterns.is_null() || options.pattern_count == 0 {
+        return AvStatus::InvalidArgument;
+    }
+    let parse = match AvPatternType::from_raw(options.pattern_type) {
+        Some(AvPatternType::Pattern) => Pattern::parse,
+        Some(AvPatternType::Prefix) => Pattern::prefix,
+        Some(AvPatternType::Suffix) => Pattern::suffix,
+        None => return AvStatus::InvalidArgument,
+    };
+    let mut patterns = Vec::with_capacity(options.pattern_count);
+    for index in 0..options.pattern_count {
+        let Some(text) = to_str(*options.patterns.add(index)) else {
+            return AvStatus::InvalidArgument;
+        };
+        match parse(text) {
+            Ok(pattern) => patterns.push(pattern),
+            Err(_) => return AvStatus::InvalidPattern,
+        }
+    }
+    let Ok(mut searcher) = Searcher::new(patterns) else {
+        return AvStatus::InvalidArgument;
+    };
+    if options.threads > 0 {
+        searcher = searcher.threads(options.threads);
+    }
+    searcher = searcher.count(options.count);
+    if options.max_seconds > 0.0 {
+        let Ok(max_time) = Duration::try_from_secs_f64(options.max_seconds) else {
+            return AvStatus::InvalidArgument;
+        };
+        searcher = searcher.max_time(max_time);
+    }
+    if options.max_attempts > 0 {
+        searcher = searcher.max_attempts(options.max_attempts);
+    }
+    let canceller = searcher.canceller();
+    *search = Box::into_raw(Box::new(AvSearch {
+        search: Mutex::new(Some(searcher.start())),
+        error: Mutex::new(None),
+        canceller,
+    }));
+    AvStatus::Ok
+}
+
+/// Waits up to `timeout_ms` milliseconds for the next match and writes it to `found`
+/// Returns `AV_TIMEOUT` if none came in time, and `AV_SEARCH_ENDED` or `AV_SEARCH_FAILED` once the search is over
+///
+/// # Safety
+/// `search` must have been returned by `av_search_start` and not been released,
+/// and `found` must point to a writable `AvMatch`.
+#[no_mangle]
+pub unsafe extern "C" fn av_search_poll(search: *const AvSearch, timeout_ms: u32, found: *mut AvMatch) -> AvStatus {
+    if search.is_null() || found.is_null() {
+        return AvStatus::InvalidArgument;
+    }
+    let search = &*search;
+    let mut running = search.search.lock().unwrap();
+    let Some(next) = running.as_mut().map(|running| running.next_timeout(Duration::from_millis(timeout_ms.into()))) else {
+        return search.ended();
+    };
+    match next {
+        Ok(next) => {
+            let found = &mut *found;
+            found.pattern = next.index;
+            found.secret_key = next.key_pair.secret_key;
+            found.public_key = next.key_pair.public_key;
+            write_address(&next.address(), &mut found.address);
+            found.attempts = next.attempts;
+            found.elapsed_seconds = next.elapsed.as_secs_f64();
+            AvStatus::Ok
+        }
+        Err(RecvTimeoutError::Timeout) => AvStatus::Timeout,
+        Err(RecvTimeoutError::Disconnected) => {
+            if let Some(Err(err)) = running.take().map(Search::wait) {
+                *search.error.lock().unwrap() = Some(err);
+            }
+            search.ended()
+        }
+    }
+}
+
+impl AvSearch {
+    fn ended(&self) -> AvStatus {
+        if self.error.lock().unwrap().is_some() {
+            AvStatus::SearchFailed
+        } else {
+            AvStatus::SearchEnded
+        }
+    }
+}
+
+/// Returns why a search failed, to be released with `av_string_free`,
+/// or null if it has not ended with `AV_SEARCH_FAILED`
+///
+/// # Safety
+/// `search` must have been returned by `av_search_start` and not been released.
+#[no_mangle]
+pub unsafe extern "C" fn av_search_error(search: *const AvSearch) -> *mut c_char {
+    let Some(search) = search.as_ref() else {
+        return ptr::null_mut();
+    };
+    match search.error.lock().unwrap().as_deref() {
+        Some(err) => CString::new(err).map_or(ptr::null_mut(), CString::into_raw),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Stops a search, `av_search_poll` then returns the matches already found followed by `AV_SEARCH_ENDED`
+/// Can be called from any thread, also while another one is polling.
+///
+/// # Safety
+/// `search` must have been returned by `av_search_start` and not been released.
+#[no_mangle]
+pub unsafe extern "C" fn av_search_cancel(search: *const AvSearch) {
+    if let Some(search) = search.as_ref() {
+        search.canceller.cancel();
+    }
+}
+
+/// Cancels the search if it is still running and releases it, null is ignored
+///
+/// # Safety
+/// `search` must have been returned by `av_search_start`, not been released before,
+/// and no other thread may use it anymore.
+#[no_mangle]
+pub unsafe extern "C" fn av_search_free(search: *mut AvSearch) {
+    if !search.is_null() {
+        drop(Box::from_raw(search));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::mem::{align_of, offset_of, size_of, size_of_val};
+    use crate::backend::{TEST_ADDRESS, TEST_SEED};
+    use super::*;
+
+    const HEADER: &str = include_str!("../include/algorand_vanity.h");
+    const NEVER: &str = "AAAAAAAAAAAA";
+
+    fn options(patterns: &[*const c_char], pattern_type: AvPatternType, count: usize) -> AvSearchOptions {
+        AvSearchOptions {
+            patterns: patterns.as_ptr(),
+            pattern_count: patterns.len(),
+            pattern_type: pattern_type as u32,
+            threads: 1,
+            count,
+            max_seconds: 0.0,
+            max_attempts: 0,
+        }
+    }
+
+    /// Polls until something else than a timeout comes back
+    unsafe fn poll(search: *const AvSearch, found: &mut AvMatch) -> AvStatus {
+        loop {
+            match av_search_poll(search, 100, found) {
+                AvStatus::Timeout => {}
+                status => return status,
+            }
+        }
+    }
+
+    #[test]
+    fn address_round_trip() {
+        let mut public_key = [0; PUBLIC_KEY_LEN];
+        let mut address = [0; ADDRESS_LEN + 1];
+        let mut decoded = [0; PUBLIC_KEY_LEN];
+        unsafe {
+            assert_eq!(av_public_key(&TEST_SEED, &mut public_key), AvStatus::Ok);
+            assert_eq!(av_encode_address(&public_key, &mut address), AvStatus::Ok);
+            assert_eq!(CStr::from_ptr(address.as_ptr()).to_str(), Ok(TEST_ADDRESS));
+            assert_eq!(av_decode_address(address.as_ptr(), &mut decoded), AvStatus::Ok);
+            address[ADDRESS_LEN - 1] = b'D' as c_char;
+            assert_eq!(av_decode_address(address.as_ptr(), &mut decoded), AvStatus::InvalidAddress);
+        }
+        assert_eq!(decoded, public_key);
+    }
+
+    #[test]
+    fn mnemonic_round_trip() {
+        let mut key = [0; SEED_LEN];
+        unsafe {
+            let text = av_mnemonic_from_key(&TEST_SEED);
+            assert!(CStr::from_ptr(text).to_str().unwrap().starts_with("horn hard liar trick"));
+            assert_eq!(av_mnemonic_to_key(text, &mut key), AvStatus::Ok);
+            av_string_free(text);
+            assert_eq!(av_mnemonic_to_key(c"abandon abandon".as_ptr(), &mut key), AvStatus::InvalidMnemonic);
+        }
+        assert_eq!(key, TEST_SEED);
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        let mut key = [0; SEED_LEN];
+        let mut address = [0; ADDRESS_LEN + 1];
+        let mut search = ptr::null_mut();
+        let mut found: AvMatch = unsafe { std::mem::zeroed() };
+        let not_utf8 = [0xffu8 as c_char, 0];
+        unsafe {
+            assert_eq!(av_public_key(ptr::null(), &mut key), AvStatus::InvalidArgument);
+            assert_eq!(av_public_key(&TEST_SEED, ptr::null_mut()), AvStatus::InvalidArgument);
+            assert_eq!(av_encode_address(ptr::null(), &mut address), AvStatus::InvalidArgument);
+            assert_eq!(av_decode_address(ptr::null(), &mut key), AvStatus::InvalidArgument);
+            assert_eq!(av_decode_address(not_utf8.as_ptr(), &mut key), AvStatus::InvalidArgument);
+            assert!(av_mnemonic_from_key(ptr::null()).is_null());
+            assert_eq!(av_mnemonic_to_key(ptr::null(), &mut key), AvStatus::InvalidArgument);
+            assert_eq!(av_search_start(ptr::null(), &mut search), AvStatus::InvalidArgument);
+            assert_eq!(av_search_poll(ptr::null(), 0, &mut found), AvStatus::InvalidArgument);
+            assert!(av_search_error(ptr::null()).is_null());
+            av_search_cancel(ptr::null());
+            av_search_free(ptr::null_mut());
+            av_string_free(ptr::null_mut());
+
+            let pattern = [c"A".as_ptr()];
+            let mut unknown_type = options(&pattern, AvPatternType::Prefix, 1);
+            unknown_type.pattern_type = 3;
+            assert_eq!(av_search_start(&unknown_type, &mut search), AvStatus::InvalidArgument);
+            let mut missing_patterns = options(&pattern, AvPatternType::Prefix, 1);
+            missing_patterns.patterns = ptr::null();
+            assert_eq!(av_search_start(&missing_patterns, &mut search), AvStatus::InvalidArgument);
+            assert_eq!(av_search_start(&options(&[], AvPatternType::Prefix, 1), &mut search), AvStatus::InvalidArgument);
+            let invalid = [c"ABC0".as_ptr()];
+            assert_eq!(av_search_start(&options(&invalid, AvPatternType::Pattern, 1), &mut search), AvStatus::InvalidPattern);
+            let anchored = [c"^A".as_ptr()];
+            assert_eq!(av_search_start(&options(&anchored, AvPatternType::Prefix, 1), &mut search), AvStatus::InvalidPattern);
+        }
+        assert!(search.is_null());
+    }
+
+    #[test]
+    fn search() {
+        let never = CString::new(NEVER).unwrap();
+        let patterns = [never.as_ptr(), c"B".as_ptr()];
+        let mut search = ptr::null_mut();
+        let mut found: AvMatch = unsafe { std::mem::zeroed() };
+        unsafe {
+            assert_eq!(av_search_start(&options(&patterns, AvPatternType::Prefix, 1), &mut search), AvStatus::Ok);
+            assert_eq!(poll(search, &mut found), AvStatus::Ok);
+            assert_eq!(poll(search, &mut found), AvStatus::SearchEnded);
+            assert_eq!(av_search_poll(search, 0, &mut found), AvStatus::SearchEnded);
+            assert!(av_search_error(search).is_null());
+            av_search_free(search);
+        }
+        assert_eq!(found.pattern, 1);
+        let address = unsafe { CStr::from_ptr(found.address.as_ptr()) }.to_str().unwrap();
+        assert!(address.starts_with('B'));
+        assert_eq!(KeyPair::from_seed(found.secret_key).address(), address);
+        assert_eq!(found.public_key, KeyPair::from_seed(found.secret_key).public_key);
+        assert!(found.attempts > 0);
+    }
+
+    #[test]
+    fn cancel() {
+        let never = CString::new(NEVER).unwrap();
+        let patterns = [never.as_ptr()];
+        let mut search = ptr::null_mut();
+        let mut found: AvMatch = unsafe { std::mem::zeroed() };
+        unsafe {
+            assert_eq!(av_search_start(&options(&patterns, AvPatternType::Pattern, 0), &mut search), AvStatus::Ok);
+            assert_eq!(av_search_poll(search, 0, &mut found), AvStatus::Timeout);
+            av_search_cancel(search);
+            assert_eq!(poll(search, &mut found), AvStatus::SearchEnded);
+            av_search_free(search);
+        }
+    }
+
+    /// The header with its comments removed
+    fn header() -> String {
+        let mut header = HEADER.to_string();
+        while let Some(start) = header.find("/*") {
+            let end = start + header[start..].find("*/").unwrap() + 2;
+            header.replace_range(start..end, "");
+        }
+        header
+    }
+
+    fn defines(header: &str) -> HashMap<String, usize> {
+        header.lines()
+            .filter_map(|line| line.strip_prefix("#define "))
+            .filter_map(|define| define.split_once(' '))
+            .filter_map(|(name, value)| Some((name.to_string(), value.trim().parse().ok()?)))
+            .collect()
+    }
+
+    /// The entries of `typedef ... { ... } name;`, separated by `separator`
+    fn typedef<'a>(header: &'a str, name: &str, separator: char) -> Vec<&'a str> {
+        let end = header.find(&format!("}} {};", name)).unwrap();
+        let start = header[..end].rfind('{').unwrap() + 1;
+        header[start..end].split(separator).map(str::trim).filter(|entry| !entry.is_empty()).collect()
+    }
+
+    fn enum_values(header: &str, name: &str) -> Vec<(String, u32)> {
+        typedef(header, name, ',').into_iter()
+            .map(|entry| {
+                let (name, value) = entry.split_once('=').unwrap();
+                (name.trim().to_string(), value.trim().parse().unwrap())
+            })
+            .collect()
+    }
+
+    /// The name, offset and size of every field of a C struct, and its size, laid out by the usual C rules
+    fn c_layout(header: &str, name: &str) -> (Vec<(String, usize, usize)>, usize) {
+        let defines = defines(header);
+        let mut fields = Vec::new();
+        let (mut offset, mut struct_align) = (0usize, 1);
+        for field in typedef(header, name, ';') {
+            let (declaration, length) = match field.split_once('[') {
+                Some((declaration, length)) => {
+                    let length = length.trim_end_matches(']').split('+').map(str::trim)
+                        .map(|term| defines.get(term).copied().unwrap_or_else(|| term.parse().unwrap()))
+                        .sum();
+                    (declaration, length)
+                }
+                None => (field, 1),
+            };
+            let (c_type, field_name) = declaration.rsplit_once([' ', '*']).unwrap();
+            let (size, align) = match c_type.trim() {
+                _ if declaration.contains('*') => (size_of::<*const u8>(), align_of::<*const u8>()),
+                "size_t" => (size_of::<usize>(), align_of::<usize>()),
+                "uint8_t" | "char" => (1, 1),
+                "uint32_t" => (size_of::<u32>(), align_of::<u32>()),
+                "uint64_t" => (size_of::<u64>(), align_of::<u64>()),
+                "double" => (size_of::<f64>(), align_of::<f64>()),
+                other => panic!("unknown C type {} in {}", other, name),
+            };
+            offset = offset.next_multiple_of(align);
+            fields.push((field_name.to_string(), offset, size * length));
+            offset += size * length;
+            struct_align = struct_align.max(align);
+        }
+        (fields, offset.next_multiple_of(struct_align))
+    }
+
+    /// The name, offset and size of a field of a Rust struct
+    macro_rules! field {
+        ($value:ident: $type:ty, $field:ident) => {
+            (stringify!($field).to_string(), offset_of!($type, $field), size_of_val(&$value.$field))
+        };
+    }
+
+    #[test]
+    fn header_matches() {
+        let header = header();
+        let defines = defines(&header);
+        assert_eq!(defines["AV_SEED_LEN"], SEED_LEN);
+        assert_eq!(defines["AV_PUBLIC_KEY_LEN"], PUBLIC_KEY_LEN);
+        assert_eq!(defines["AV_ADDRESS_LEN"], ADDRESS_LEN);
+
+        let statuses = [
+            ("AV_OK", AvStatus::Ok),
+            ("AV_INVALID_ARGUMENT", AvStatus::InvalidArgument),
+            ("AV_INVALID_MNEMONIC", AvStatus::InvalidMnemonic),
+            ("AV_INVALID_ADDRESS", AvStatus::InvalidAddress),
+            ("AV_INVALID_PATTERN", AvStatus::InvalidPattern),
+            ("AV_TIMEOUT", AvStatus::Timeout),
+            ("AV_SEARCH_ENDED", AvStatus::SearchEnded),
+            ("AV_SEARCH_FAILED", AvStatus::SearchFailed),
+        ];
+        let statuses: Vec<_> = statuses.iter().map(|&(name, status)| (name.to_string(), status as u32)).collect();
+        assert_eq!(enum_values(&header, "av_status"), statuses);
+        let pattern_types = [
+            ("AV_PATTERN", AvPatternType::Pattern),
+            ("AV_PREFIX", AvPatternType::Prefix),
+            ("AV_SUFFIX", AvPatternType::Suffix),
+        ];
+        let pattern_types: Vec<_> = pattern_types.iter().map(|&(name, value)| (name.to_string(), value as u32)).collect();
+        assert_eq!(enum_values(&header, "av_pattern_type"), pattern_types);
+
+        let options: AvSearchOptions = unsafe { std::mem::zeroed() };
+        let fields = vec![
+            field!(options: AvSearchOptions, patterns),
+            field!(options: AvSearchOptions, pattern_count),
+            field!(options: AvSearchOptions, pattern_type),
+            field!(options: AvSearchOptions, threads),
+            field!(options: AvSearchOptions, count),
+            field!(options: AvSearchOptions, max_seconds),
+            field!(options: AvSearchOptions, max_attempts),
+        ];
+        assert_eq!(c_layout(&header, "av_search_options"), (fields, size_of::<AvSearchOptions>()));
+        let found: AvMatch = unsafe { std::mem::zeroed() };
+        let fields = vec![
+            field!(found: AvMatch, pattern),
+            field!(found: AvMatch, secret_key),
+            field!(found: AvMatch, public_key),
+            field!(found: AvMatch, address),
+            field!(found: AvMatch, attempts),
+            field!(found: AvMatch, elapsed_seconds),
+        ];
+        assert_eq!(c_layout(&header, "av_match"), (fields, size_of::<AvMatch>()));
+    }
+}
@@ -1,117 +1,436 @@
-use std::{fs};
-use ocl::{ProQue, Buffer, MemFlags};
-// use sha2::{Sha512, Digest};
+use std::fs;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use ocl::{ProQue, Buffer, Kernel, MemFlags, Platform, Device};
+use ocl::enums::{DeviceInfo, DeviceInfoResult};
 
-use ring::signature::{Ed25519KeyPair};
-use crate::xoshiro256::Xoshiro256;
+use crate::backend::{Found, SearchBackend, Update, TEST_ADDRESS, TEST_SEED};
+use crate::checkpoint::{Checkpoint, Worker};
+use crate::crypto::{hex, KeyPair, PUBLIC_KEY_CHARS, SEED_LEN};
+use crate::matcher::Matcher;
+use crate::pattern::Pattern;
+use crate::seeds::{SeedStream, Streams};
 
-use base32::{Alphabet, encode as b32encode};
-
-const DIMS: usize = 1;
 const PUBLIC_KEY_LEN: usize = 32;
-const SEED_LEN: usize = 32;
-const U64_LEN: usize = 8;
+/// Number of consecutive seeds every work item tests in a single kernel launch
+const ITERATIONS: u32 = 32;
+/// Length of the prefix searched for by the self-test
+const SELF_TEST_CHARS: usize = 4;
+
+
+/// The kernel is assembled from these pieces in order
+const KERNEL_FILES: [&str; 3] = ["sha512.cl", "ed25519.cl", "search.cl"];
+const KERNEL_SOURCES: [&str; 3] = [include_str!("cl/sha512.cl"), include_str!("cl/ed25519.cl"), include_str!("cl/search.cl")];
+
+
+/// Assembles the kernel from the pieces compiled into the binary,
+/// or from the pieces in `kernel_path` when developing the kernel
+fn load_kernel(kernel_path: Option<&Path>) -> Result<String, String> {
+    let sources = match kernel_path {
+        Some(dir) => KERNEL_FILES.iter()
+            .map(|file| {
+                let path = dir.join(file);
+                fs::read_to_string(&path).map_err(|err| format!("Failed to read the kernel piece {}: {}", path.display(), err))
+            })
+            .collect::<Result<Vec<_>, _>>()?,
+        None => KERNEL_SOURCES.iter().map(|source| source.to_string()).collect(),
+    };
+    Ok(sources.join("\n"))
+}
 
+/// Starting seeds for `count` work items, see `SeedStream`
+/// The kernel derives `ITERATIONS` seeds from each of them by counting up their last 8 bytes
+pub fn generate_seeds(seed_stream: &mut SeedStream, count: usize) -> Vec<u8> {
+    let mut seeds = vec![0u8; count * SEED_LEN];
+    seed_stream.fill(&mut seeds);
+    seeds
+}
+
+/// The counter the kernel counts up in the last 8 bytes of a seed, see `generate_seeds`
+fn counter(seed: &[u8]) -> u64 {
+    u64::from_le_bytes(seed[SEED_LEN - 8..SEED_LEN].try_into().unwrap())
+}
 
-fn load_kernel() -> String {
-    fs::read_to_string("src/cl/chimera.cl").expect("Bruh, no such file")
+/// Every device of every platform, in the order they are listed by `list_devices`
+fn all_devices() -> Result<Vec<(usize, Platform, usize, Device)>, ocl::Error> {
+    let mut devices = Vec::new();
+    for (p, platform) in ocl::core::get_platform_ids()?.into_iter().map(Platform::new).enumerate() {
+        for (d, device) in Device::list_all(platform)?.into_iter().enumerate() {
+            devices.push((p, platform, d, device));
+        }
+    }
+    Ok(devices)
 }
 
-pub fn generate_seeds(rng: &mut Xoshiro256, count: usize) -> Vec<u8> {
-    let len: usize = (count * SEED_LEN / U64_LEN) as usize;
-    let mut seeds = vec![0u64; len];
-    for i in 0..len {
-        seeds[i] = rng.next();
+/// Prints every OpenCL platform and device along with the selectors accepted by `select_device`
+pub fn list_devices() -> Result<(), ocl::Error> {
+    let devices = all_devices()?;
+    if devices.is_empty() {
+        println!("No OpenCL devices found");
     }
-    let (prefix, mid, suffix);
-    unsafe {
-        (prefix, mid, suffix) = seeds.align_to::<u8>();
+    for (index, (p, platform, d, device)) in devices.iter().enumerate() {
+        if *d == 0 {
+            println!("Platform {}: {} ({})", p, platform.name()?, platform.version()?);
+        }
+        let memory = match device.info(DeviceInfo::GlobalMemSize)? {
+            DeviceInfoResult::GlobalMemSize(bytes) => bytes >> 20,
+            _ => 0,
+        };
+        println!("  [{}] {}:{} {}", index, p, d, device.name()?);
+        println!("      Compute units: {}", device.info(DeviceInfo::MaxComputeUnits)?);
+        println!("      Global memory: {} MiB", memory);
+        println!("      Driver version: {}", device.info(DeviceInfo::DriverVersion)?);
     }
-    assert!(prefix.is_empty());
-    assert!(suffix.is_empty());
-    return mid.to_vec();
+    Ok(())
 }
 
-pub struct Ed25519KeyPairDebug {
-    pub private_scalar: [u8; 32],
+/// Picks a device by `PLATFORM:DEVICE` indices, by its index over all platforms,
+/// or by part of its own or its platform's name, as listed by `list_devices`
+/// Without a selector the first device of the default platform is used
+fn select_device(selector: Option<&str>) -> Result<(Platform, Device), ocl::Error> {
+    let selector = match selector {
+        Some(selector) => selector,
+        None => {
+            let platform = Platform::new(ocl::core::default_platform()?);
+            return Ok((platform, Device::first(platform)?));
+        }
+    };
+    let devices = all_devices()?;
+    let by_indices = selector.split_once(':')
+        .and_then(|(p, d)| Some((p.parse::<usize>().ok()?, d.parse::<usize>().ok()?)));
+    let needle = selector.to_lowercase();
+    for (index, (p, platform, d, device)) in devices.iter().enumerate() {
+        let matches = match (by_indices, selector.parse::<usize>()) {
+            (Some(indices), _) => indices == (*p, *d),
+            (None, Ok(wanted)) => wanted == index,
+            (None, Err(_)) => {
+                device.name()?.to_lowercase().contains(&needle) || platform.name()?.to_lowercase().contains(&needle)
+            }
+        };
+        if matches {
+            return Ok((*platform, *device));
+        }
+    }
+    Err(format!("No OpenCL device matches {}, run the devices command to list them", selector).into())
+}
 
-    pub private_prefix: [u8; 32],
+/// Recomputes a seed reported by the kernel on the CPU
+/// The hit is only accepted if the CPU derives the same public key as the GPU
+/// and the resulting address really matches, anything else is a kernel fault
+fn verify_hit(matcher: &Matcher, seed: &[u8], gpu_public_key: &[u8]) -> Result<KeyPair, String> {
+    let kp = KeyPair::from_seed(seed.try_into().unwrap());
+    if kp.public_key[..] != gpu_public_key[..] {
+        return Err(format!("the GPU computed public key {} but the CPU computed {}",
+            hex(gpu_public_key), hex(&kp.public_key)));
+    }
+    if matcher.find(&kp).is_none() {
+        return Err(format!("the GPU reported {} which does not match", kp.address()));
+    }
+    Ok(kp)
+}
 
-    pub public_key: [u8; 32],
+/// Number of work items needed to keep every compute unit of the device busy
+fn global_size(device: &Device) -> Result<usize, ocl::Error> {
+    let compute_units = match device.info(DeviceInfo::MaxComputeUnits)? {
+        DeviceInfoResult::MaxComputeUnits(units) => units as usize,
+        _ => 1,
+    };
+    Ok(compute_units * device.max_wg_size()?)
 }
 
+/// Converts OpenCL errors for `SearchBackend`
+fn opencl(err: ocl::Error) -> String {
+    format!("OpenCL error: {}", err)
+}
 
-pub(crate) fn run(prefix: String) -> Result<(), ocl::Error> {
-    let mut rng = Xoshiro256::from_entropy();
-    let src =  load_kernel();
+/// A seed the kernel reported, with the public key it derived
+struct Hit {
+    /// The work item which found it
+    item: usize,
+    seed: Vec<u8>,
+    public_key: Vec<u8>,
+}
 
-    let pro_que = ProQue::builder()
-        .src(src)
-        .dims(DIMS)
-        .build()?;
+/// The kernel with its buffers, set up to search for one prefix
+struct Batch {
+    kernel: Kernel,
+    found: Buffer<u8>,
+    public_keys: Buffer<u8>,
+    /// Holds the starting seeds, matching seeds are written back by the kernel
+    seeds: Buffer<u8>,
+    /// Read by the kernel, so it has to live as long as the kernel does
+    _prefix: Buffer<u8>,
+    dims: usize,
+}
 
+impl Batch {
+    fn new(pro_que: &ProQue, prefix: &str) -> Result<Batch, ocl::Error> {
+        let dims = pro_que.dims().to_len();
+        let found = Buffer::<u8>::builder()
+            .queue(pro_que.queue().clone())
+            .len(dims)
+            .build()?;
 
-    let found_buffer = Buffer::<u8>::builder()
-        .queue(pro_que.queue().clone())
-        .len(DIMS)
-        .build()?;
+        let public_keys = Buffer::<u8>::builder()
+            .queue(pro_que.queue().clone())
+            .len(PUBLIC_KEY_LEN * dims)
+            .fill_val(0u8)
+            .build()?;
 
-    let pk_buffer = Buffer::<u8>::builder()
-        .queue(pro_que.queue().clone())
-        .len(PUBLIC_KEY_LEN * DIMS)
-        .fill_val(0u8)
-        .build()?;
+        let seeds = Buffer::<u8>::builder()
+            .queue(pro_que.queue().clone())
+            .len(SEED_LEN * dims)
+            .flags(MemFlags::READ_WRITE)
+            .build()?;
 
-    // pro_que.create_buffer()
-    // let mut seeds = generate_seeds(&mut rng, DIMS);
-    let mut seeds: Vec<u8> = vec![108, 75, 154, 1, 135, 158, 88, 246, 92, 77, 139, 103, 47, 229, 239, 40, 220, 185, 84, 75, 117, 203, 247, 26, 91, 7, 240, 156, 134, 212, 162, 234];
-    println!("Seeds: {:?}", seeds);
-    let seed_buffer;
-    unsafe {
-        seed_buffer = Buffer::<u8>::builder()
+        let prefix_buffer = Buffer::<u8>::builder()
             .queue(pro_que.queue().clone())
-            .len(seeds.len())
-            .use_host_slice(&seeds)
+            .len(prefix.len())
+            .copy_host_slice(prefix.as_bytes())
             .flags(MemFlags::READ_ONLY)
             .build()?;
-    };
-    
-    let prefix_buffer = Buffer::<u8>::builder()
-        .queue(pro_que.queue().clone())
-        .len(prefix.len())
-        .copy_host_slice(prefix.as_bytes())
-        .flags(MemFlags::READ_ONLY)
-        .build()?;
-
-    let kernel = pro_que.kernel_builder("brute_force_b32_prefix")
-        .arg(&found_buffer)
-        .arg(&pk_buffer)
-        .arg(&seed_buffer)
-        .arg(&prefix_buffer)
-        .arg(prefix.len() as u32)
-        .arg(1u32)
-        .build()?;
-
-    unsafe { kernel.enq()?; }
-
-    let mut public_keys: Vec<u8> = vec![0u8; pk_buffer.len()];
-    let mut found: Vec<u8> = vec![0u8; DIMS];
-    found_buffer.read(&mut found).enq()?;
-    pk_buffer.read(&mut public_keys).enq()?;
-
-    // let acc = Account::from_seed(seeds[0..32].try_into().unwrap());
-    // println!("Public key: {:?}", acc.raw_public_key());
-    // seeds.reverse();
-    let kp = Ed25519KeyPair::from_seed_unchecked(&seeds[0..32]).unwrap();
-    let kp_debug: Ed25519KeyPairDebug = unsafe {
-        std::mem::transmute(kp)
-    };
 
-    println!("Public key: {:?}", kp_debug.public_key);
-    println!("Public key base32: {:?}", b32encode(Alphabet::RFC4648 { padding: true }, &kp_debug.public_key));
-    // let sha = Sha512::digest(&seeds);
-    // println!("SHA512: {:?}", sha);
+        let kernel = pro_que.kernel_builder("brute_force_b32_prefix")
+            .arg(&found)
+            .arg(&public_keys)
+            .arg(&seeds)
+            .arg(&prefix_buffer)
+            .arg(prefix.len() as u32)
+            .arg(ITERATIONS)
+            .build()?;
 
-    println!("The value at index [{}] is now '{:?}'!", 0, &public_keys[0..32]);
-    Ok(())
-}
\ No newline at end of file
+        Ok(Batch { kernel, found, public_keys, seeds, _prefix: prefix_buffer, dims })
+    }
+
+    /// Runs the kernel once on the starting `seeds` of every work item
+    fn launch(&self, seeds: &[u8]) -> Result<Vec<Hit>, ocl::Error> {
+        self.seeds.write(seeds).enq()?;
+        self.found.cmd().fill(0u8, None).enq()?;
+
+        unsafe { self.kernel.enq()?; }
+
+        let mut found = vec![0u8; self.dims];
+        self.found.read(&mut found).enq()?;
+        if found.iter().all(|&f| f == 0) {
+            return Ok(Vec::new());
+        }
+        let mut seeds = vec![0u8; SEED_LEN * self.dims];
+        self.seeds.read(&mut seeds).enq()?;
+        let mut public_keys = vec![0u8; PUBLIC_KEY_LEN * self.dims];
+        self.public_keys.read(&mut public_keys).enq()?;
+        Ok(found.iter().enumerate()
+            .zip(seeds.chunks_exact(SEED_LEN).zip(public_keys.chunks_exact(PUBLIC_KEY_LEN)))
+            .filter(|((_, &f), _)| f != 0)
+            .map(|((item, _), (seed, public_key))| Hit { item, seed: seed.to_vec(), public_key: public_key.to_vec() })
+            .collect())
+    }
+}
+
+/// A search in progress
+struct Running {
+    batch: Batch,
+    matcher: Arc<Matcher>,
+    seed_stream: SeedStream,
+    stream: u64,
+}
+
+/// Searches for addresses starting with a single plain prefix with an OpenCL kernel
+/// Every hit is verified on the CPU, see `verify_hit`
+/// The starting seeds are taken from the first stream of the job
+pub struct GpuBackend {
+    selector: Option<String>,
+    source: String,
+    /// Set up on first use, so unsupported patterns are refused before touching OpenCL
+    pro_que: Option<ProQue>,
+    running: Option<Running>,
+}
+
+impl GpuBackend {
+    /// Reads the kernel from `kernel_path` if given, see `load_kernel`,
+    /// and runs it on the device picked by `selector`, see `select_device`
+    pub fn new(kernel_path: Option<&Path>, selector: Option<&str>) -> Result<GpuBackend, String> {
+        let source = load_kernel(kernel_path)?;
+        Ok(GpuBackend { selector: selector.map(str::to_string), source, pro_que: None, running: None })
+    }
+
+    /// Selects the device and compiles the kernel for it
+    fn pro_que(&mut self) -> Result<&ProQue, ocl::Error> {
+        if self.pro_que.is_none() {
+            let (platform, device) = select_device(self.selector.as_deref())?;
+            self.pro_que = Some(ProQue::builder()
+                .platform(platform)
+                .device(device)
+                .src(self.source.clone())
+                .dims(global_size(&device)?)
+                .build()?);
+        }
+        Ok(self.pro_que.as_ref().unwrap())
+    }
+}
+
+impl SearchBackend for GpuBackend {
+    fn device(&self) -> String {
+        match &self.pro_que {
+            Some(pro_que) => format!("{} with {} work items", pro_que.device().name().unwrap_or_default(), pro_que.dims().to_len()),
+            None => "GPU".to_string(),
+        }
+    }
+
+    fn supports(&self, patterns: &[Pattern]) -> Result<(), String> {
+        match patterns.first().and_then(Pattern::literal_prefix) {
+            Some(prefix) if patterns.len() == 1 && prefix.len() <= PUBLIC_KEY_CHARS => Ok(()),
+            Some(_) if patterns.len() == 1 => Err(format!("The GPU only compares the first {} characters of an address", PUBLIC_KEY_CHARS)),
+            _ => Err("The GPU only supports searching for a single plain prefix".to_string()),
+        }
+    }
+
+    /// Searches for the start of the address of the test seed, which is given to the first work item
+    fn self_test(&mut self) -> Result<(), String> {
+        let prefix = &TEST_ADDRESS[..SELF_TEST_CHARS];
+        let batch = Batch::new(self.pro_que().map_err(opencl)?, prefix).map_err(opencl)?;
+        let mut seeds = generate_seeds(&mut SeedStream::new(SeedStream::random_key(), 0), batch.dims);
+        seeds[..SEED_LEN].copy_from_slice(&TEST_SEED);
+        let hits = batch.launch(&seeds).map_err(opencl)?;
+        let matcher = Matcher::new(vec![Pattern::prefix(prefix).unwrap()]);
+        for hit in &hits {
+            verify_hit(&matcher, &hit.seed, &hit.public_key)?;
+        }
+        match hits.iter().find(|hit| hit.item == 0) {
+            Some(hit) if hit.seed[..] == TEST_SEED[..] => Ok(()),
+            _ => Err(format!("the GPU did not find the address {} of the test seed", TEST_ADDRESS)),
+        }
+    }
+
+    fn start(&mut self, matcher: Arc<Matcher>, streams: Streams, resume: &Checkpoint) -> Result<(), String> {
+        let prefix = matcher.pattern(0).literal_prefix().expect("the GPU only searches for plain prefixes");
+        let batch = Batch::new(self.pro_que().map_err(opencl)?, &prefix).map_err(opencl)?;
+        let stream = streams.number(0);
+        let seed_stream = streams.open(0, resume.position(stream));
+        self.running = Some(Running { batch, matcher, seed_stream, stream });
+        Ok(())
+    }
+
+    /// Launches the kernel until `timeout` has passed or a hit came in
+    fn poll(&mut self, timeout: Duration) -> Result<Update, String> {
+        let Some(running) = &mut self.running else {
+            return Err("The GPU search has not been started".to_string());
+        };
+        let start = Instant::now();
+        let mut update = Update::default();
+        while update.matches.is_empty() && update.faults.is_empty() && start.elapsed() < timeout {
+            let seeds = generate_seeds(&mut running.seed_stream, running.batch.dims);
+            let hits = running.batch.launch(&seeds).map_err(opencl)?;
+            let counted = update.attempts;
+            update.attempts += running.batch.dims as u64 * ITERATIONS as u64;
+            for hit in hits {
+                // the work items before it and the seeds of its own before the hit count as tried
+                let start = &seeds[hit.item * SEED_LEN..(hit.item + 1) * SEED_LEN];
+                let iteration = counter(&hit.seed).wrapping_sub(counter(start)) % ITERATIONS as u64;
+                let attempts = counted + hit.item as u64 * ITERATIONS as u64 + iteration + 1;
+                match verify_hit(&running.matcher, &hit.seed, &hit.public_key) {
+                    Ok(key_pair) => update.matches.push(Found { pattern: 0, key_pair, attempts }),
+                    Err(fault) => update.faults.push(fault),
+                }
+            }
+        }
+        update.positions = vec![Worker { stream: running.stream, position: running.seed_stream.position() }];
+        Ok(update)
+    }
+
+    fn cancel(&mut self) {
+        self.running = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The seed the kernel tries `steps` iterations after `seed`
+    fn advance(seed: [u8; SEED_LEN], steps: u64) -> [u8; SEED_LEN] {
+        let mut next = seed;
+        next[24..].copy_from_slice(&counter(&seed).wrapping_add(steps).to_le_bytes());
+        next
+    }
+
+    /// A fresh directory for kernel pieces
+    fn kernel_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("algorand-vanity-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn built_in_kernel() {
+        let source = load_kernel(None).unwrap();
+        assert_eq!(source, KERNEL_SOURCES.join("\n"));
+        assert!(source.contains("brute_force_b32_prefix"));
+    }
+
+    #[test]
+    fn kernel_path_replaces_built_in_kernel() {
+        let dir = kernel_dir("kernel");
+        for file in KERNEL_FILES {
+            fs::write(dir.join(file), format!("// {}", file)).unwrap();
+        }
+        let source = load_kernel(Some(&dir));
+        fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(source.unwrap(), "// sha512.cl\n// ed25519.cl\n// search.cl");
+    }
+
+    #[test]
+    fn missing_kernel_piece() {
+        let dir = kernel_dir("missing-kernel");
+        fs::write(dir.join("sha512.cl"), "// sha512").unwrap();
+        let err = load_kernel(Some(&dir)).unwrap_err();
+        let backend = GpuBackend::new(Some(&dir), None).err();
+        fs::remove_dir_all(&dir).unwrap();
+        assert!(err.starts_with("Failed to read the kernel piece"), "{}", err);
+        assert!(err.contains(&dir.join("ed25519.cl").display().to_string()), "{}", err);
+        assert_eq!(backend, Some(err));
+    }
+
+    #[test]
+    fn forged_hits_are_rejected() {
+        let kp = KeyPair::from_seed(TEST_SEED);
+        let matcher = Matcher::new(vec![Pattern::prefix(&TEST_ADDRESS[..SELF_TEST_CHARS]).unwrap()]);
+        assert_eq!(verify_hit(&matcher, &TEST_SEED, &kp.public_key).unwrap().address(), TEST_ADDRESS);
+
+        let mut forged = kp.public_key;
+        forged[31] ^= 1;
+        let fault = verify_hit(&matcher, &TEST_SEED, &forged).err().unwrap();
+        assert!(fault.starts_with("the GPU computed public key"), "{}", fault);
+
+        let other = Matcher::new(vec![Pattern::prefix("AAAA").unwrap()]);
+        let fault = verify_hit(&other, &TEST_SEED, &kp.public_key).err().unwrap();
+        assert!(fault.ends_with("which does not match"), "{}", fault);
+    }
+
+    #[test]
+    #[ignore = "needs an OpenCL device, such as POCL"]
+    fn self_test_on_default_device() {
+        GpuBackend::new(None, None).unwrap().self_test().unwrap();
+    }
+
+    /// Gives the first work item a seed whose counter carries over during the launch
+    /// and searches for the address of the seed after the carry
+    #[test]
+    #[ignore = "needs an OpenCL device, such as POCL"]
+    fn counter_carries_across_bytes() {
+        let mut backend = GpuBackend::new(None, None).unwrap();
+        for (counter, steps) in [(0xff, 1), (0xfff0, 31), (0x00ff_ffff_ffff_ffff, 1), (u64::MAX - 2, 5)] {
+            let mut start = TEST_SEED;
+            start[24..].copy_from_slice(&u64::to_le_bytes(counter));
+            let expected = advance(start, steps);
+            let prefix = KeyPair::from_seed(expected).address()[..SELF_TEST_CHARS].to_string();
+            let batch = Batch::new(backend.pro_que().unwrap(), &prefix).unwrap();
+            let mut seeds = generate_seeds(&mut SeedStream::new(SeedStream::random_key(), 0), batch.dims);
+            seeds[..SEED_LEN].copy_from_slice(&start);
+            let hits = batch.launch(&seeds).unwrap();
+            let hit = hits.iter().find(|hit| hit.item == 0).expect("the first work item found nothing");
+            assert_eq!(hit.seed[..], expected[..], "counter {:#x} plus {}", counter, steps);
+        }
+    }
+}
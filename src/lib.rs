@@ -0,0 +1,41 @@
+//! Generates Algorand accounts whose address matches a pattern
+//!
+//! Besides the `algorand-vanity` command line tool, the key, address and mnemonic handling and the
+//! CPU search can be used directly, see `Searcher` for running a search in-process.
+//! On `wasm32` only the keys, mnemonics and patterns are built, along with the `wasm` bindings,
+//! as the threads, sockets and OpenCL of the other modules aren't available there.
+
+#[cfg(not(target_arch = "wasm32"))]
+pub mod backend;
+pub mod checkpoint;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod cpu;
+pub mod crypto;
+pub mod difficulty;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod distributed;
+#[cfg(all(feature = "ffi", not(target_arch = "wasm32")))]
+pub mod ffi;
+#[cfg(all(feature = "gpu", not(target_arch = "wasm32")))]
+pub mod gpu;
+pub mod matcher;
+pub mod mnemonic;
+pub mod pattern;
+#[cfg(all(feature = "python", not(target_arch = "wasm32")))]
+mod python;
+pub mod search;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod searcher;
+pub mod seeds;
+#[cfg(target_arch = "wasm32")]
+pub mod wasm;
+#[cfg(any(test, not(target_arch = "wasm32")))]
+mod test_vectors;
+mod wordlist;
+
+pub use crypto::{decode_address, encode_address, AddressError, KeyPair};
+pub use matcher::Matcher;
+pub use mnemonic::MnemonicError;
+pub use pattern::{Pattern, PatternError};
+#[cfg(not(target_arch = "wasm32"))]
+pub use searcher::{Canceller, Match, Search, Searcher};
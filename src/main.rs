@@ -1,25 +1,550 @@
-mod cpu;
-mod mnemonic;
-mod wordlist;
-mod crypto;
-mod gpu;
-mod xoshiro256;
-
-use crate::cpu::run as run_cpu;
-use crate::gpu::run as run_gpu;
-use std::env;
-
-fn main() {
-    let input: Vec<String> = env::args().collect();
-    if input.len() != 3 {
-        println!("Usage: {} [--cpu|--gpu] PREFIX", input[0]);
-        return;
-    }
-    let mode = input[1].clone();
-    let prefix = input[2].clone();
-    match mode.as_str() {
-        "--cpu" => run_cpu(prefix, 8),
-        "--gpu" => run_gpu(prefix).unwrap(),
-        _ => println!("Unknown mode: {}", mode)
+use algorand_vanity::backend::{self, SearchBackend};
+use algorand_vanity::checkpoint::{Checkpoint, Checkpointer};
+use algorand_vanity::cpu::CpuBackend;
+#[cfg(feature = "gpu")]
+use algorand_vanity::gpu;
+use algorand_vanity::crypto::{decode_address, from_hex, KeyPair, PUBLIC_KEY_CHARS, PUBLIC_KEY_LEN, SEED_LEN};
+use algorand_vanity::difficulty::{format_duration, Difficulty, CONFIDENCE_LEVELS};
+use algorand_vanity::{distributed, mnemonic};
+use algorand_vanity::pattern::{Pattern, PatternError};
+use algorand_vanity::matcher::Matcher;
+use algorand_vanity::search::{Backend, Console, Format, Job, Limits, Reporter, Summary};
+use algorand_vanity::seeds::{Shard, Streams};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use std::net::{TcpListener, TcpStream};
+use std::num::NonZeroUsize;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+/// Exit code for searches which ended before finding every requested match,
+/// and for keys which don't match any of the given patterns
+const EXIT_NO_MATCH: u8 = 3;
+/// Exit code for invalid arguments, the same clap uses
+const EXIT_USAGE: u8 = 2;
+/// Reported when the GPU is used in a build without it
+#[cfg(not(feature = "gpu"))]
+const NO_GPU: &str = "This build has no GPU support, rebuild it with --features gpu";
+/// How long `bench` measures by default, in seconds
+const BENCH_SECONDS: u64 = 10;
+
+/// Generate vanity addresses on Algorand
+#[derive(Parser)]
+#[command(version, about, after_help = "Exit codes: 0 on success, 1 on errors, 2 on invalid arguments, \
+    3 if a search stopped before finding every match or a key does not match")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Search for addresses matching any of the patterns
+    Search(SearchArgs),
+    /// Show how hard it is to find a match, without searching
+    Estimate(EstimateArgs),
+    /// Show the address of a mnemonic or private key and check it against patterns
+    Verify(VerifyArgs),
+    /// Find a mnemonic in which one word is missing, unknown or mistyped
+    Recover(RecoverArgs),
+    /// Hand out a search to workers connecting over the network and collect their matches
+    Coordinator(CoordinatorArgs),
+    /// Join a coordinator and search with the local backend until it stops the search
+    Worker(WorkerArgs),
+    /// Measure how many keys per second a backend searches
+    Bench(BenchArgs),
+    /// List the OpenCL platforms and devices
+    Devices,
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum PatternType {
+    /// Anchors, fixed positions, wildcards and classes as described in the README
+    Pattern,
+    /// The start of the address
+    Prefix,
+    /// The end of the address
+    Suffix,
+}
+
+/// The backends a search can run on locally
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum LocalBackend {
+    Cpu,
+    Gpu,
+}
+
+impl From<LocalBackend> for Backend {
+    fn from(backend: LocalBackend) -> Backend {
+        match backend {
+            LocalBackend::Cpu => Backend::Cpu,
+            LocalBackend::Gpu => Backend::Gpu,
+        }
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Human readable text with a progress line
+    Text,
+    /// One JSON object per match on stdout, progress as NDJSON events on stderr
+    Json,
+}
+
+impl From<OutputFormat> for Format {
+    fn from(format: OutputFormat) -> Format {
+        match format {
+            OutputFormat::Text => Format::Text,
+            OutputFormat::Json => Format::Json,
+        }
+    }
+}
+
+#[derive(Args)]
+struct BackendArgs {
+    /// Where to search for keys
+    #[arg(long, value_enum, default_value_t = LocalBackend::Cpu)]
+    backend: LocalBackend,
+    /// Number of CPU threads [default: number of logical CPUs]
+    #[arg(short, long)]
+    threads: Option<NonZeroUsize>,
+    /// OpenCL device as PLATFORM:DEVICE, an index or part of a name, see devices
+    #[arg(long, value_name = "SELECTOR")]
+    device: Option<String>,
+    /// Read the OpenCL kernel pieces from DIR instead of the built-in ones
+    #[arg(long, value_name = "DIR")]
+    kernel_path: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct LimitArgs {
+    /// Stop after N distinct matches, 0 searches until another limit is reached
+    #[arg(short = 'n', long, value_name = "N", default_value_t = 1)]
+    count: usize,
+    /// Stop after searching for SECONDS
+    #[arg(long, value_name = "SECONDS")]
+    max_time: Option<u64>,
+    /// Stop after trying N keys
+    #[arg(long, value_name = "N")]
+    max_attempts: Option<u64>,
+}
+
+impl LimitArgs {
+    fn limits(&self) -> Limits {
+        Limits {
+            count: Some(self.count).filter(|&count| count > 0),
+            max_time: self.max_time.map(Duration::from_secs),
+            max_attempts: self.max_attempts,
+            cancel: None,
+        }
+    }
+}
+
+#[derive(Args)]
+struct OutputArgs {
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+    /// Only print matches
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+#[derive(Args)]
+struct SearchArgs {
+    #[command(flatten)]
+    backend: BackendArgs,
+    /// How the patterns are read
+    #[arg(long = "type", value_enum, default_value_t = PatternType::Pattern)]
+    pattern_type: PatternType,
+    #[command(flatten)]
+    limits: LimitArgs,
+    #[command(flatten)]
+    output: OutputArgs,
+    /// Save the state of the search to FILE every minute
+    #[arg(long, value_name = "FILE")]
+    checkpoint: Option<PathBuf>,
+    /// Continue the search saved in the checkpoint file, with its patterns
+    #[arg(long, requires = "checkpoint", conflicts_with = "patterns")]
+    resume: bool,
+    /// Search slice I of N, for running one search as N processes with the same --session-seed
+    #[arg(long, value_name = "I/N", requires = "session_seed")]
+    shard: Option<Shard>,
+    /// Secret 32 byte hex key all keys are drawn from, instead of a random one
+    #[arg(long, value_name = "HEX", value_parser = parse_seed)]
+    session_seed: Option<[u8; SEED_LEN]>,
+    /// Patterns to search for, an address matching any of them is a match
+    #[arg(required_unless_present = "resume")]
+    patterns: Vec<String>,
+}
+
+#[derive(Args)]
+struct CoordinatorArgs {
+    /// Address to accept workers on, such as 192.168.1.10:7878
+    #[arg(long, value_name = "ADDRESS")]
+    listen: String,
+    /// Shared secret the workers have to present before they are given the session key
+    #[arg(long)]
+    token: String,
+    /// How the patterns are read
+    #[arg(long = "type", value_enum, default_value_t = PatternType::Pattern)]
+    pattern_type: PatternType,
+    #[command(flatten)]
+    limits: LimitArgs,
+    #[command(flatten)]
+    output: OutputArgs,
+    /// Patterns to search for, an address matching any of them is a match
+    #[arg(required = true)]
+    patterns: Vec<String>,
+}
+
+#[derive(Args)]
+struct WorkerArgs {
+    /// Address of the coordinator
+    #[arg(long, value_name = "ADDRESS")]
+    connect: String,
+    /// Shared secret given to the coordinator
+    #[arg(long)]
+    token: String,
+    #[command(flatten)]
+    backend: BackendArgs,
+    /// Only print matches and errors
+    #[arg(short, long)]
+    quiet: bool,
+}
+
+#[derive(Args)]
+struct EstimateArgs {
+    /// Also show how long a search takes at this rate
+    #[arg(long, value_name = "KEYS_PER_SECOND", value_parser = parse_rate)]
+    rate: Option<f64>,
+    /// How the patterns are read
+    #[arg(long = "type", value_enum, default_value_t = PatternType::Pattern)]
+    pattern_type: PatternType,
+    #[arg(required = true)]
+    patterns: Vec<String>,
+}
+
+#[derive(Args)]
+#[group(multiple = false)]
+struct KeyArgs {
+    /// The 25 word mnemonic, read from stdin if neither this nor --seed is given
+    #[arg(long, value_name = "PHRASE")]
+    mnemonic: Option<String>,
+    /// The 32 byte private key seed as hex
+    #[arg(long, value_name = "HEX", value_parser = parse_seed)]
+    seed: Option<[u8; SEED_LEN]>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    key: KeyArgs,
+    /// How the patterns are read
+    #[arg(long = "type", value_enum, default_value_t = PatternType::Pattern)]
+    pattern_type: PatternType,
+    /// Patterns the address should match, any of them is enough
+    patterns: Vec<String>,
+}
+
+#[derive(Args)]
+struct RecoverArgs {
+    /// The mnemonic with the unknown word written as ?, read from stdin if not given
+    #[arg(long, value_name = "PHRASE")]
+    mnemonic: Option<String>,
+    /// Only accept mnemonics of this address
+    #[arg(long, value_parser = parse_address)]
+    address: Option<[u8; PUBLIC_KEY_LEN]>,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    backend: BackendArgs,
+    /// How long to measure
+    #[arg(long, default_value_t = BENCH_SECONDS)]
+    seconds: u64,
+    /// How the patterns are read
+    #[arg(long = "type", value_enum, default_value_t = PatternType::Pattern)]
+    pattern_type: PatternType,
+    /// Patterns to estimate the search time for at the measured rate
+    patterns: Vec<String>,
+}
+
+/// Why a command failed
+enum Failure {
+    /// Arguments which clap can't check on its own, like patterns read according to --type
+    Usage(String),
+    Error(String),
+}
+
+impl From<String> for Failure {
+    fn from(err: String) -> Failure {
+        Failure::Error(err)
+    }
+}
+
+fn main() -> ExitCode {
+    let result = match Cli::parse().command {
+        Command::Search(args) => search(args),
+        Command::Estimate(args) => estimate(args),
+        Command::Verify(args) => verify(args),
+        Command::Recover(args) => recover(args),
+        Command::Coordinator(args) => coordinate(args),
+        Command::Worker(args) => work(args),
+        Command::Bench(args) => bench(args),
+        Command::Devices => devices(),
+    };
+    result.unwrap_or_else(|failure| match failure {
+        Failure::Usage(err) => {
+            eprintln!("Error: {}", err);
+            ExitCode::from(EXIT_USAGE)
+        }
+        Failure::Error(err) => {
+            eprintln!("Error: {}", err);
+            ExitCode::FAILURE
+        }
+    })
+}
+
+fn parse_pattern(text: &str, pattern_type: PatternType) -> Result<Pattern, PatternError> {
+    match pattern_type {
+        PatternType::Pattern => Pattern::parse(text),
+        PatternType::Prefix => Pattern::prefix(text),
+        PatternType::Suffix => Pattern::suffix(text),
+    }
+}
+
+/// Parses all patterns, explaining the first invalid one
+fn parse_patterns(texts: &[String], pattern_type: PatternType) -> Result<Vec<Pattern>, String> {
+    texts.iter().map(|text| {
+        parse_pattern(text, pattern_type).map_err(|err| format!("Invalid pattern {}: {}", text, err))
+    }).collect()
+}
+
+/// Runs the search on the selected backend
+fn run_search(args: &BackendArgs, patterns: Vec<Pattern>, job: Job) -> Result<Summary, String> {
+    let mut backend: Box<dyn SearchBackend> = match args.backend {
+        LocalBackend::Cpu => {
+            let threads = args.threads
+                .or_else(|| std::thread::available_parallelism().ok())
+                .map_or(1, NonZeroUsize::get);
+            Box::new(CpuBackend::new(threads))
+        }
+        LocalBackend::Gpu => gpu_backend(args)?,
+    };
+    backend::run(backend.as_mut(), Matcher::new(patterns), job)
+}
+
+#[cfg(feature = "gpu")]
+fn gpu_backend(args: &BackendArgs) -> Result<Box<dyn SearchBackend>, String> {
+    Ok(Box::new(gpu::GpuBackend::new(args.kernel_path.as_deref(), args.device.as_deref())?))
+}
+
+#[cfg(not(feature = "gpu"))]
+fn gpu_backend(_args: &BackendArgs) -> Result<Box<dyn SearchBackend>, String> {
+    Err(NO_GPU.to_string())
+}
+
+#[cfg(feature = "gpu")]
+fn devices() -> Result<ExitCode, Failure> {
+    gpu::list_devices()
+        .map(|_| ExitCode::SUCCESS)
+        .map_err(|err| format!("Failed to list OpenCL devices: {}", err).into())
+}
+
+#[cfg(not(feature = "gpu"))]
+fn devices() -> Result<ExitCode, Failure> {
+    Err(NO_GPU.to_string().into())
+}
+
+fn search(args: SearchArgs) -> Result<ExitCode, Failure> {
+    let (patterns, initial) = match &args.checkpoint {
+        Some(path) if args.resume => {
+            let mut checkpoint = Checkpoint::load(path)
+                .map_err(|err| format!("Failed to read the checkpoint {}: {}", path.display(), err))?;
+            // without the session seed the streams get a fresh key, on which the old positions mean nothing
+            if args.session_seed.is_none() {
+                checkpoint.restart_streams();
+            }
+            (parse_patterns(&checkpoint.patterns, PatternType::Pattern)?, checkpoint)
+        }
+        _ => {
+            let patterns = parse_patterns(&args.patterns, args.pattern_type).map_err(Failure::Usage)?;
+            let checkpoint = Checkpoint::new(&patterns);
+            (patterns, checkpoint)
+        }
+    };
+    let limits = args.limits.limits();
+    let console = Console::new(args.output.format.into(), args.output.quiet, args.backend.backend.into());
+    let job = Job {
+        limits: limits.clone(),
+        reporter: &console,
+        streams: session_streams(args.session_seed, args.shard),
+        checkpointer: Checkpointer::new(args.checkpoint, initial),
+    };
+    let summary = run_search(&args.backend, patterns, job)?;
+    Ok(exit_code(&limits, &summary))
+}
+
+/// The streams of the given shard under the session seed, or all streams under a random key without one
+fn session_streams(session_seed: Option<[u8; SEED_LEN]>, shard: Option<Shard>) -> Streams {
+    match session_seed {
+        Some(key) => shard.unwrap_or(Shard { index: 1, count: 1 }).streams(key),
+        None => Streams::random(),
+    }
+}
+
+/// Success if the search found every match it was asked for
+fn exit_code(limits: &Limits, summary: &Summary) -> ExitCode {
+    if limits.count.is_none() || limits.enough_matches(summary.matches) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::from(EXIT_NO_MATCH)
+    }
+}
+
+/// Runs a search spread over the workers which connect to the listening address
+fn coordinate(args: CoordinatorArgs) -> Result<ExitCode, Failure> {
+    let patterns = parse_patterns(&args.patterns, args.pattern_type).map_err(Failure::Usage)?;
+    let listener = TcpListener::bind(&args.listen).map_err(|err| format!("Failed to listen on {}: {}", args.listen, err))?;
+    let limits = args.limits.limits();
+    let console = Console::new(args.output.format.into(), args.output.quiet, Backend::Distributed);
+    if let Ok(address) = listener.local_addr() {
+        console.info(&format!("Waiting for workers on {}", address));
+    }
+    let summary = distributed::coordinate(listener, &args.token, patterns, limits.clone(), &console)?;
+    Ok(exit_code(&limits, &summary))
+}
+
+/// Searches on the local backend for a coordinator
+fn work(args: WorkerArgs) -> Result<ExitCode, Failure> {
+    let stream = TcpStream::connect(&args.connect).map_err(|err| format!("Failed to connect to {}: {}", args.connect, err))?;
+    let console = Console::new(Format::Text, args.quiet, args.backend.backend.into());
+    distributed::work(stream, &args.token, &console, |patterns, job| run_search(&args.backend, patterns, job))?;
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Prints how hard it is to find a match without starting a search
+fn estimate(args: EstimateArgs) -> Result<ExitCode, Failure> {
+    let patterns = parse_patterns(&args.patterns, args.pattern_type).map_err(Failure::Usage)?;
+    print_estimate(&Difficulty::of(&patterns), args.rate);
+    Ok(ExitCode::SUCCESS)
+}
+
+fn print_estimate(difficulty: &Difficulty, rate: Option<f64>) {
+    let describe = |attempts: f64| match rate {
+        Some(rate) => format!("{:.3e} attempts, {}", attempts, format_duration(attempts / rate)),
+        None => format!("{:.3e} attempts", attempts),
+    };
+    println!("Probability per key: 1 in {:.3e}", 1.0 / difficulty.probability());
+    println!("Expected: {}", describe(difficulty.expected_attempts()));
+    for confidence in CONFIDENCE_LEVELS {
+        println!("{}% chance: {}", confidence * 100.0, describe(difficulty.attempts_for(confidence)));
+    }
+}
+
+/// Prints the address of a key and whether it matches any of the patterns
+fn verify(args: VerifyArgs) -> Result<ExitCode, Failure> {
+    let seed = read_key(&args.key)?;
+    let patterns = parse_patterns(&args.patterns, args.pattern_type).map_err(Failure::Usage)?;
+    let kp = KeyPair::from_seed(seed);
+    println!("Address: {}", kp.address());
+    if patterns.is_empty() {
+        return Ok(ExitCode::SUCCESS);
+    }
+    let matcher = Matcher::new(patterns);
+    match matcher.find(&kp) {
+        Some(pattern) => {
+            println!("Matches pattern: {}", matcher.pattern(pattern));
+            Ok(ExitCode::SUCCESS)
+        }
+        None => {
+            println!("Does not match any pattern");
+            Ok(ExitCode::from(EXIT_NO_MATCH))
+        }
+    }
+}
+
+/// Reads the key given on the command line, or a mnemonic from stdin,
+/// which keeps it out of the shell history
+fn read_key(args: &KeyArgs) -> Result<[u8; SEED_LEN], String> {
+    if let Some(seed) = args.seed {
+        return Ok(seed);
+    }
+    mnemonic::to_key(&read_phrase(&args.mnemonic)?).map_err(|err| format!("Invalid mnemonic: {}", err))
+}
+
+/// The mnemonic given on the command line, or else the first line of stdin
+fn read_phrase(phrase: &Option<String>) -> Result<String, String> {
+    match phrase {
+        Some(phrase) => Ok(phrase.clone()),
+        None => {
+            let mut phrase = String::new();
+            std::io::stdin().read_line(&mut phrase).map_err(|err| format!("Failed to read the mnemonic: {}", err))?;
+            Ok(phrase)
+        }
+    }
+}
+
+/// The public key of an address, verifying its checksum
+fn parse_address(address: &str) -> Result<[u8; PUBLIC_KEY_LEN], String> {
+    decode_address(address.trim()).map_err(|err| err.to_string())
+}
+
+fn parse_rate(text: &str) -> Result<f64, String> {
+    match text.parse::<f64>() {
+        Ok(rate) if rate > 0.0 && rate.is_finite() => Ok(rate),
+        _ => Err("The rate must be a positive number of keys per second".to_string()),
+    }
+}
+
+fn parse_seed(hex: &str) -> Result<[u8; SEED_LEN], String> {
+    from_hex(hex).and_then(|seed| seed.try_into().ok())
+        .ok_or_else(|| format!("The seed must be {} bytes written as {} hex digits", SEED_LEN, SEED_LEN * 2))
+}
+
+/// Prints every mnemonic which differs from the given one in a single word and is valid,
+/// only accepting those of the expected address if there is one
+fn recover(args: RecoverArgs) -> Result<ExitCode, Failure> {
+    let phrase = read_phrase(&args.mnemonic)?;
+    let keys = mnemonic::recover(&phrase, |key| {
+        args.address.is_none_or(|public_key| KeyPair::from_seed(*key).public_key == public_key)
+    }).map_err(|err| format!("Invalid mnemonic: {}", err))?;
+    for key in &keys {
+        println!("Mnemonic: {}", mnemonic::from_key(key));
+        println!("Address: {}", KeyPair::from_seed(*key).address());
+    }
+    match keys.len() {
+        0 => {
+            println!("No valid mnemonic found");
+            Ok(ExitCode::from(EXIT_NO_MATCH))
+        }
+        count if args.address.is_none() && count > 1 => {
+            println!("{} candidates found, pass --address to tell them apart", count);
+            Ok(ExitCode::SUCCESS)
+        }
+        _ => Ok(ExitCode::SUCCESS),
+    }
+}
+
+/// Searches for a pattern which practically never matches and reports the key rate
+fn bench(args: BenchArgs) -> Result<ExitCode, Failure> {
+    let patterns = parse_patterns(&args.patterns, args.pattern_type).map_err(Failure::Usage)?;
+    // Covers the whole public key, so the time is spent on generating keys rather than on matches
+    let unreachable = Pattern::prefix(&"A".repeat(PUBLIC_KEY_CHARS)).unwrap();
+    let limits = Limits { max_time: Some(Duration::from_secs(args.seconds)), ..Limits::default() };
+    let console = Console::new(Format::Text, true, args.backend.backend.into());
+    let job = Job {
+        limits,
+        reporter: &console,
+        streams: Streams::random(),
+        checkpointer: Checkpointer::new(None, Checkpoint::new(&[])),
+    };
+    let summary = run_search(&args.backend, vec![unreachable], job)?;
+    let rate = summary.keys_per_second();
+    println!("{} keys in {}: {:.0} keys/s", summary.attempts, format_duration(summary.elapsed.as_secs_f64()), rate);
+    if !patterns.is_empty() {
+        print_estimate(&Difficulty::of(&patterns), Some(rate));
     }
+    Ok(ExitCode::SUCCESS)
 }
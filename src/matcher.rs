@@ -0,0 +1,174 @@
+use crate::crypto::{symbol_at, KeyPair, ADDRESS_LEN, PUBLIC_KEY_CHARS};
+use crate::pattern::Pattern;
+
+/// Up to this many patterns are compared one by one instead of walking the tries
+const LINEAR_SCAN_LIMIT: usize = 4;
+
+/// A trie over base32 symbols, every node lists the patterns whose indexed symbols end there
+struct Trie {
+    nodes: Vec<Node>,
+}
+
+#[derive(Default)]
+struct Node {
+    /// Index of the child for every symbol, 0 if there is none (the root is never a child)
+    children: [u32; 32],
+    patterns: Vec<usize>,
+}
+
+impl Trie {
+    fn new() -> Trie {
+        Trie { nodes: vec![Node::default()] }
+    }
+
+    fn insert(&mut self, symbols: impl Iterator<Item = u8>, pattern: usize) {
+        let mut node = 0;
+        for symbol in symbols {
+            let child = self.nodes[node].children[symbol as usize] as usize;
+            node = if child == 0 {
+                self.nodes.push(Node::default());
+                let child = self.nodes.len() - 1;
+                self.nodes[node].children[symbol as usize] = child as u32;
+                child
+            } else {
+                child
+            };
+        }
+        self.nodes[node].patterns.push(pattern);
+    }
+
+    /// Follows `symbol(depth)` down the trie and offers every pattern on the way to `verify`
+    /// Returns the first pattern accepted by `verify`
+    fn walk(&self, symbol: impl Fn(usize) -> u8, mut verify: impl FnMut(usize) -> bool) -> Option<usize> {
+        let mut node = &self.nodes[0];
+        for depth in 0..=PUBLIC_KEY_CHARS {
+            if let Some(&pattern) = node.patterns.iter().find(|&&p| verify(p)) {
+                return Some(pattern);
+            }
+            if depth == PUBLIC_KEY_CHARS {
+                break;
+            }
+            match node.children[symbol(depth) as usize] {
+                0 => break,
+                child => node = &self.nodes[child as usize],
+            }
+        }
+        None
+    }
+}
+
+/// Matches addresses against many patterns at once
+/// Patterns are indexed by their leading or trailing characters which depend on the public key alone,
+/// so the cost of a lookup does not grow with the number of patterns
+pub struct Matcher {
+    patterns: Vec<Pattern>,
+    /// Prefixes indexed from the first character onward, patterns which can't be indexed sit in the root
+    prefixes: Trie,
+    /// Suffixes indexed from the last public key character backward
+    suffixes: Trie,
+}
+
+impl Matcher {
+    pub fn new(patterns: Vec<Pattern>) -> Matcher {
+        let mut prefixes = Trie::new();
+        let mut suffixes = Trie::new();
+        for (index, pattern) in patterns.iter().enumerate() {
+            if pattern.start() == 0 {
+                prefixes.insert((0..PUBLIC_KEY_CHARS).map_while(|i| pattern.symbol(i)), index);
+            } else if pattern.end() == ADDRESS_LEN {
+                suffixes.insert((pattern.start()..PUBLIC_KEY_CHARS).rev().map_while(|i| pattern.symbol(i)), index);
+            } else {
+                prefixes.insert(std::iter::empty(), index);
+            }
+        }
+        Matcher { patterns, prefixes, suffixes }
+    }
+
+    pub fn patterns(&self) -> &[Pattern] {
+        &self.patterns
+    }
+
+    pub fn pattern(&self, index: usize) -> &Pattern {
+        &self.patterns[index]
+    }
+
+    /// Returns the index of a pattern matching the address of the key pair
+    /// The checksum is only calculated for patterns whose public key characters already match
+    pub fn find(&self, kp: &KeyPair) -> Option<usize> {
+        let mut address = None;
+        let mut verify = |index: usize| {
+            let pattern = &self.patterns[index];
+            pattern.matches_public_key(&kp.public_key)
+                && (!pattern.needs_checksum() || pattern.matches_checksum(address.get_or_insert_with(|| kp.address_bytes())))
+        };
+        if self.patterns.len() <= LINEAR_SCAN_LIMIT {
+            return (0..self.patterns.len()).find(|&index| verify(index));
+        }
+        self.prefixes.walk(|depth| symbol_at(&kp.public_key, depth), &mut verify)
+            .or_else(|| self.suffixes.walk(|depth| symbol_at(&kp.public_key, PUBLIC_KEY_CHARS - 1 - depth), &mut verify))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::test_vectors::{ADDRESS, SEED};
+    use super::*;
+
+
+    fn matcher(patterns: &[&str]) -> Matcher {
+        Matcher::new(patterns.iter().map(|text| Pattern::parse(text).unwrap()).collect())
+    }
+
+    fn matches(pattern: &str) -> bool {
+        matcher(&[pattern]).find(&KeyPair::from_seed(SEED)).is_some()
+    }
+
+    #[test]
+    fn single_patterns() {
+        assert_eq!(KeyPair::from_seed(SEED).address(), ADDRESS);
+        for (pattern, expected) in [
+            ("HVSA", true), ("HVSB", false), ("^hvsap", true),
+            ("C7IC4$", true), ("C7IA4$", false),
+            ("DYS6M7LI2DSXEDXCXDI2C7IC4$", true), ("DYS6M7LI2DSXEDXCXDI2C7IE4$", false),
+            ("@10:SBHX", true), ("@11:SBHX", false), ("@53:C7IC4", true), ("@53:C7IC", true), ("@52:C7IC", false),
+            ("?VSA", true), ("??SB", false), ("@20:??", true),
+            ("[AH]V[RST]A", true), ("[AB]VSA", false), ("HVSAP43LTSSBHXB575COY4BHNNYNUK57ADYS6M7LI2DSXEDXCXDI2C7I[AC][E4]$", true),
+            ("HVSAP43LTSSBHXB575COY4BHNNYNUK57ADYS6M7LI2DSXEDXCXDI2C7I[AC][AE]$", false),
+            (ADDRESS, true),
+        ] {
+            assert_eq!(matches(pattern), expected, "{}", pattern);
+        }
+    }
+
+    /// Up to `LINEAR_SCAN_LIMIT` patterns are compared in order, beyond it the tries are walked,
+    /// which must accept exactly the same keys
+    #[test]
+    fn linear_scan_and_tries_agree() {
+        let patterns = ["A", "B?", "@20:C", "[AE]$", "QE", "@5:[FG]H", "7", "2Q$"];
+        for count in [LINEAR_SCAN_LIMIT, LINEAR_SCAN_LIMIT + 1, patterns.len()] {
+            let combined = matcher(&patterns[..count]);
+            let single: Vec<Matcher> = patterns[..count].iter().map(|p| matcher(&[p])).collect();
+            for byte in 0..=255 {
+                let kp = KeyPair::from_seed([byte; 32]);
+                let expected: Vec<usize> = (0..count).filter(|&i| single[i].find(&kp).is_some()).collect();
+                match combined.find(&kp) {
+                    Some(found) => assert!(expected.contains(&found), "{} matched {}", kp.address(), patterns[found]),
+                    None => assert!(expected.is_empty(), "{} matches {:?}", kp.address(), expected),
+                }
+            }
+        }
+    }
+
+    /// Suffixes within the last 7 characters depend on the checksum alone and sit at the root of the suffix trie
+    #[test]
+    fn short_suffixes_in_trie() {
+        let kp = KeyPair::from_seed(SEED);
+        let others = ["B", "C", "D", "E", "F"];
+        for suffix in ["4$", "C4$", "I2C7IC4$", "DI2C7IC4$"] {
+            let patterns: Vec<&str> = others.iter().copied().chain([suffix]).collect();
+            assert_eq!(matcher(&patterns).find(&kp), Some(others.len()), "{}", suffix);
+        }
+        assert_eq!(matcher(&["B", "C", "D", "E", "F", "A4$"]).find(&kp), None);
+        assert_eq!(Pattern::parse("2C7IC4$").unwrap().start(), PUBLIC_KEY_CHARS + 1);
+    }
+}
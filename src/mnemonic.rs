@@ -3,18 +3,51 @@ This file is a slight modification of the mnemonic module from the algonaut proj
 */
 
 
+use std::fmt;
 use sha2::Digest;
 use crate::wordlist::WORDLIST;
 
 const BITS_PER_WORD: usize = 11;
 const MNEMONIC_DELIM: &str = " ";
+const KEY_LEN: usize = 32;
+/// Words encoding the key, followed by the checksum word
+pub const KEY_WORDS: usize = (KEY_LEN * 8).div_ceil(BITS_PER_WORD);
+pub const MNEMONIC_WORDS: usize = KEY_WORDS + 1;
+/// Stands for an unknown word when recovering a mnemonic
+pub const UNKNOWN_WORD: &str = "?";
+/// Words can be abbreviated down to this many letters, which are unique in the wordlist
+const PREFIX_LEN: usize = 4;
 type ChecksumAlg = sha2::Sha512_256;
 
+#[derive(Debug, PartialEq)]
+pub enum MnemonicError {
+    WrongLength { words: usize },
+    /// The word at `index`, counting from 0, is not in the wordlist
+    UnknownWord { word: String, index: usize },
+    /// The last key word sets bits past the end of the key
+    UnusedBits,
+    BadChecksum,
+    /// More than one word is unknown, too many to recover
+    TooManyUnknown { count: usize },
+}
+
+impl fmt::Display for MnemonicError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MnemonicError::WrongLength { words } => write!(f, "Mnemonic has {} words, but must have {}", words, MNEMONIC_WORDS),
+            MnemonicError::UnknownWord { word, index } => write!(f, "Word {} '{}' is not in the wordlist", index + 1, word),
+            MnemonicError::UnusedBits => write!(f, "Word {} encodes bits past the end of the key, it is misspelled or misplaced", KEY_WORDS),
+            MnemonicError::BadChecksum => write!(f, "Checksum word does not match, a word is missing, misspelled or swapped"),
+            MnemonicError::TooManyUnknown { count } => write!(f, "{} words are unknown, only one can be recovered", count),
+        }
+    }
+}
+
 // Assumes little-endian
 fn to_u11_array(bytes: &[u8]) -> Vec<u32> {
     let mut buf = 0u32;
     let mut bit_count = 0;
-    let mut out = Vec::with_capacity((bytes.len() * 8 + BITS_PER_WORD - 1) / BITS_PER_WORD);
+    let mut out = Vec::with_capacity((bytes.len() * 8).div_ceil(BITS_PER_WORD));
     for &b in bytes {
         buf |= (u32::from(b)) << bit_count;
         bit_count += 8;
@@ -30,6 +63,26 @@ fn to_u11_array(bytes: &[u8]) -> Vec<u32> {
     out
 }
 
+// Inverse of to_u11_array, the last byte holds any bits left over
+fn to_byte_array(nums: &[u32]) -> Vec<u8> {
+    let mut buf = 0u32;
+    let mut bit_count = 0;
+    let mut out = Vec::with_capacity((nums.len() * BITS_PER_WORD).div_ceil(8));
+    for &n in nums {
+        buf |= n << bit_count;
+        bit_count += BITS_PER_WORD as u32;
+        while bit_count >= 8 {
+            out.push(buf as u8);
+            buf >>= 8;
+            bit_count -= 8;
+        }
+    }
+    if bit_count != 0 {
+        out.push(buf as u8);
+    }
+    out
+}
+
 // Returns a word corresponding to the 11 bit checksum of the data
 fn checksum_word(data: &[u8; 32]) -> &str {
     let d = ChecksumAlg::digest(data);
@@ -44,4 +97,196 @@ pub fn from_key(key: &[u8; 32]) -> String {
     let mut words: Vec<_> = to_u11_array(key).into_iter().map(| v | WORDLIST[v as usize] ).collect();
     words.push(check_word);
     words.join(MNEMONIC_DELIM)
-}
\ No newline at end of file
+}
+
+/// Index of a word in the wordlist, also accepting any unique abbreviation of at least 4 letters
+pub fn lookup(word: &str) -> Option<usize> {
+    let word = word.to_lowercase();
+    if let Some(index) = WORDLIST.iter().position(|&w| w == word) {
+        return Some(index);
+    }
+    if word.len() < PREFIX_LEN {
+        return None;
+    }
+    let mut candidates = WORDLIST.iter().enumerate().filter(|(_, w)| w.starts_with(&word));
+    match (candidates.next(), candidates.next()) {
+        (Some((index, _)), None) => Some(index),
+        _ => None,
+    }
+}
+
+/// Converts the wordlist indices of a mnemonic back into the key, verifying the checksum
+pub fn from_indices(indices: &[usize; MNEMONIC_WORDS]) -> Result<[u8; 32], MnemonicError> {
+    let nums: Vec<u32> = indices[..KEY_WORDS].iter().map(|&i| i as u32).collect();
+    let bytes = to_byte_array(&nums);
+    if bytes[KEY_LEN..].iter().any(|&b| b != 0) {
+        return Err(MnemonicError::UnusedBits);
+    }
+    let key: [u8; 32] = bytes[..KEY_LEN].try_into().unwrap();
+    if checksum_word(&key) != WORDLIST[indices[KEY_WORDS]] {
+        return Err(MnemonicError::BadChecksum);
+    }
+    Ok(key)
+}
+
+/// Converts a 25 word mnemonic back into the 32-byte key, the inverse of `from_key`
+/// Words are separated by whitespace, case is ignored and words may be abbreviated to their first 4 letters
+pub fn to_key(mnemonic: &str) -> Result<[u8; 32], MnemonicError> {
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    if words.len() != MNEMONIC_WORDS {
+        return Err(MnemonicError::WrongLength { words: words.len() });
+    }
+    let mut indices = [0usize; MNEMONIC_WORDS];
+    for (index, (word, slot)) in words.iter().zip(indices.iter_mut()).enumerate() {
+        *slot = lookup(word).ok_or_else(|| MnemonicError::UnknownWord { word: word.to_string(), index })?;
+    }
+    from_indices(&indices)
+}
+
+/// Finds the keys of a mnemonic in which one word is missing, unknown or mistyped
+///
+/// A word written as `?` or not found in the wordlist is the one replaced, if every word is known
+/// each position is tried in turn, and with a word missing one is inserted at every position.
+/// Returns every distinct key whose checksum validates and which `accept` agrees to,
+/// which should compare the address as the checksum alone lets through about one candidate per position
+pub fn recover(mnemonic: &str, accept: impl Fn(&[u8; 32]) -> bool) -> Result<Vec<[u8; 32]>, MnemonicError> {
+    let words: Vec<&str> = mnemonic.split_whitespace().collect();
+    let known: Vec<Option<usize>> = words.iter()
+        .map(|&word| if word == UNKNOWN_WORD { None } else { lookup(word) })
+        .collect();
+    let unknown: Vec<usize> = (0..known.len()).filter(|&i| known[i].is_none()).collect();
+    if unknown.len() > 1 {
+        return Err(MnemonicError::TooManyUnknown { count: unknown.len() });
+    }
+    let base: Vec<usize> = known.iter().map(|index| index.unwrap_or(0)).collect();
+    let (positions, insert) = match (words.len(), unknown.first()) {
+        (MNEMONIC_WORDS, Some(&position)) => (position..position + 1, false),
+        (MNEMONIC_WORDS, None) => (0..MNEMONIC_WORDS, false),
+        (KEY_WORDS, None) => (0..MNEMONIC_WORDS, true),
+        (words, _) => return Err(MnemonicError::WrongLength { words }),
+    };
+    let mut keys = Vec::new();
+    for position in positions {
+        for candidate in 0..WORDLIST.len() {
+            let mut indices = base.clone();
+            if insert {
+                indices.insert(position, candidate);
+            } else {
+                indices[position] = candidate;
+            }
+            if let Ok(key) = from_indices(&indices.try_into().unwrap()) {
+                if !keys.contains(&key) && accept(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+    }
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto::KeyPair;
+    use crate::test_vectors::{MNEMONIC, SEED};
+    use super::*;
+
+    /// The zero key, as in the tests of the Algorand SDKs
+    const ZERO_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon \
+        abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon invest";
+    const ZERO_ADDRESS: &str = "HNVCPPGOW2SC2YVDVDICU3YNONSTEFLXDXREHJR2YBEKDC2Z3IUZSC6YGI";
+
+    fn replace_word(phrase: &str, index: usize, word: &str) -> String {
+        let mut words: Vec<&str> = phrase.split_whitespace().collect();
+        words[index] = word;
+        words.join(" ")
+    }
+
+    #[test]
+    fn known_mnemonics() {
+        assert_eq!(from_key(&[0; 32]), ZERO_MNEMONIC);
+        let key = to_key(ZERO_MNEMONIC).unwrap();
+        assert_eq!(key, [0; 32]);
+        assert_eq!(KeyPair::from_seed(key).address(), ZERO_ADDRESS);
+
+        assert_eq!(from_key(&SEED), MNEMONIC);
+        assert_eq!(to_key(MNEMONIC).unwrap(), SEED);
+    }
+
+    #[test]
+    fn round_trip() {
+        for byte in 0..=255u8 {
+            let key: [u8; 32] = std::array::from_fn(|i| byte.wrapping_mul(31).wrapping_add(i as u8 * 7));
+            assert_eq!(to_key(&from_key(&key)).unwrap(), key);
+        }
+    }
+
+    #[test]
+    fn abbreviated_words() {
+        let abbreviated: Vec<String> = MNEMONIC.split_whitespace().map(|word| word[..word.len().min(4)].to_uppercase()).collect();
+        assert_eq!(to_key(&abbreviated.join("  ")).unwrap(), SEED);
+        assert_eq!(lookup("abandon"), Some(0));
+        assert_eq!(lookup("aban"), Some(0));
+        assert_eq!(lookup("abando"), Some(0));
+        // Too short, and shared by abandon, ability, able, about, above and absent
+        assert_eq!(lookup("ab"), None);
+        assert_eq!(lookup("abandons"), None);
+    }
+
+    #[test]
+    fn misspelled_word() {
+        assert_eq!(to_key(&replace_word(MNEMONIC, 2, "lair")), Err(MnemonicError::UnknownWord { word: "lair".to_string(), index: 2 }));
+        assert_eq!(to_key(&replace_word(MNEMONIC, 0, "hor")), Err(MnemonicError::UnknownWord { word: "hor".to_string(), index: 0 }));
+    }
+
+    #[test]
+    fn wrong_checksum() {
+        assert_eq!(to_key(&replace_word(MNEMONIC, KEY_WORDS, "abandon")), Err(MnemonicError::BadChecksum));
+        assert_eq!(to_key(&replace_word(MNEMONIC, 3, "trim")), Err(MnemonicError::BadChecksum));
+        // The last key word only carries 3 bits of the key
+        assert_eq!(to_key(&replace_word(ZERO_MNEMONIC, KEY_WORDS - 1, "zoo")), Err(MnemonicError::UnusedBits));
+    }
+
+    fn is_seed(key: &[u8; 32]) -> bool {
+        KeyPair::from_seed(*key).address() == KeyPair::from_seed(SEED).address()
+    }
+
+    #[test]
+    fn recover_unknown_word() {
+        let phrase = replace_word(MNEMONIC, 6, UNKNOWN_WORD);
+        assert_eq!(recover(&phrase, is_seed).unwrap(), vec![SEED]);
+        // Only the checksum tells the candidates apart without an address
+        let candidates = recover(&phrase, |_| true).unwrap();
+        assert!(candidates.contains(&SEED) && candidates.len() > 1, "{} candidates", candidates.len());
+        // A word which is not in the wordlist is the unknown one
+        assert_eq!(recover(&replace_word(MNEMONIC, 6, "helmat"), is_seed).unwrap(), vec![SEED]);
+    }
+
+    #[test]
+    fn recover_mistyped_word() {
+        let phrase = replace_word(MNEMONIC, 11, "second");
+        assert_eq!(to_key(&phrase), Err(MnemonicError::BadChecksum));
+        assert_eq!(recover(&phrase, is_seed).unwrap(), vec![SEED]);
+    }
+
+    #[test]
+    fn recover_missing_word() {
+        for index in [0, 9, KEY_WORDS] {
+            let mut words: Vec<&str> = MNEMONIC.split_whitespace().collect();
+            words.remove(index);
+            assert_eq!(recover(&words.join(" "), is_seed).unwrap(), vec![SEED], "word {} missing", index);
+        }
+    }
+
+    #[test]
+    fn recover_errors() {
+        let phrase = replace_word(&replace_word(MNEMONIC, 1, UNKNOWN_WORD), 4, UNKNOWN_WORD);
+        assert_eq!(recover(&phrase, is_seed), Err(MnemonicError::TooManyUnknown { count: 2 }));
+        assert_eq!(recover("abandon abandon", is_seed), Err(MnemonicError::WrongLength { words: 2 }));
+    }
+
+    #[test]
+    fn wrong_length() {
+        assert_eq!(to_key("abandon abandon"), Err(MnemonicError::WrongLength { words: 2 }));
+        assert_eq!(to_key(&format!("{} abandon", MNEMONIC)), Err(MnemonicError::WrongLength { words: 26 }));
+    }
+}
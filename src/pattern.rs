@@ -0,0 +1,377 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::crypto::{symbol_at, symbol_value, ADDRESS_CHECKSUM_LEN, ADDRESS_LEN, ALPHABET_CHARS, LAST_CHARS, PUBLIC_KEY_CHARS, PUBLIC_KEY_LEN};
+
+/// Every symbol is allowed
+const ANY: u32 = u32::MAX;
+const PUBLIC_KEY_BITS: usize = PUBLIC_KEY_LEN * 8;
+const ADDRESS_BITS: usize = (PUBLIC_KEY_LEN + ADDRESS_CHECKSUM_LEN) * 8;
+
+#[derive(Debug, PartialEq)]
+pub enum PatternError {
+    Empty,
+    InvalidCharacter { character: char, position: usize },
+    UnclosedClass { position: usize },
+    EmptyClass { position: usize },
+    InvalidPosition,
+    /// The pattern is longer than an address
+    TooLong { length: usize },
+    /// The pattern extends past the end of an address
+    OutOfBounds { start: usize },
+    /// A pattern anchored at both ends must cover the whole address
+    WrongLength,
+    /// The last character of an address can't take any of the given values
+    UnreachableLastChar { position: usize },
+    /// A search was given no patterns at all, so it could never find a match
+    NoPatterns,
+}
+
+impl fmt::Display for PatternError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PatternError::Empty => write!(f, "Pattern is empty"),
+            PatternError::InvalidCharacter { character, position } => {
+                write!(f, "Character '{}' at position {} never appears in an address (A-Z, 2-7)", character, position)?;
+                match lookalike(*character) {
+                    Some(suggestion) => write!(f, ", did you mean '{}'?", suggestion),
+                    None => Ok(()),
+                }
+            }
+            PatternError::UnclosedClass { position } => write!(f, "Character class at position {} is missing a closing ']'", position),
+            PatternError::EmptyClass { position } => write!(f, "Character class at position {} is empty", position),
+            PatternError::InvalidPosition => write!(f, "Fixed positions must be written as @INDEX:PATTERN"),
+            PatternError::TooLong { length } => {
+                write!(f, "Pattern covers {} characters, but addresses are only {} characters long", length, ADDRESS_LEN)
+            }
+            PatternError::OutOfBounds { start } => {
+                write!(f, "Pattern starting at character {} extends past the end of a {} character address", start, ADDRESS_LEN)
+            }
+            PatternError::WrongLength => write!(f, "Pattern anchored at both ends must be exactly {} characters long", ADDRESS_LEN),
+            PatternError::UnreachableLastChar { position } => write!(
+                f,
+                "Character at position {} falls on the last address character, which only carries 3 bits and is always one of: {}",
+                position, LAST_CHARS
+            ),
+            PatternError::NoPatterns => write!(f, "At least one pattern is required"),
+        }
+    }
+}
+
+/// A compiled pattern, which constrains a run of consecutive address characters
+///
+/// Characters are compiled into bit masks over the public key and the checksum,
+/// so most candidates are rejected by a few masked word compares.
+/// Classes which can't be expressed as a mask are checked one character at a time afterwards
+///
+/// Syntax:
+/// * `ABC` or `^ABC` - the address starts with ABC
+/// * `ABC$` - the address ends with ABC
+/// * `@10:ABC` - ABC starts at character 10, counting from 0
+/// * `?` - any character
+/// * `[AEIOU]` - one of the listed characters
+///
+/// Lowercase letters are accepted and folded to uppercase
+#[derive(Clone, Debug)]
+pub struct Pattern {
+    text: String,
+    /// Index of the first address character covered by the pattern
+    start: usize,
+    /// Allowed symbols for every covered character, one bit per symbol
+    classes: Vec<u32>,
+    /// Public key bits fixed by the pattern, as big-endian words
+    public_key_mask: [u64; 4],
+    public_key_bits: [u64; 4],
+    /// Checksum bits fixed by the pattern
+    checksum_mask: u32,
+    checksum_bits: u32,
+    /// Indices of characters whose class isn't fully described by the masks
+    inexact: Vec<usize>,
+}
+
+impl Pattern {
+    pub fn parse(text: &str) -> Result<Pattern, PatternError> {
+        let (body, offset, start_anchor, end_anchor) = if let Some(rest) = text.strip_prefix('@') {
+            let (index, body) = rest.split_once(':').ok_or(PatternError::InvalidPosition)?;
+            let index: usize = index.parse().map_err(|_| PatternError::InvalidPosition)?;
+            (body, text.len() - body.len(), Some(index), false)
+        } else {
+            let body = text.strip_prefix('^').unwrap_or(text);
+            let offset = text.len() - body.len();
+            match body.strip_suffix('$') {
+                Some(body) if offset == 0 => (body, offset, None, true),
+                Some(body) => (body, offset, Some(0), true),
+                None => (body, offset, Some(0), false),
+            }
+        };
+        Pattern::anchored(text.to_string(), body, offset, start_anchor, end_anchor)
+    }
+
+    /// Parses `text` as the start of the address, without the anchors and fixed positions of `parse`
+    pub fn prefix(text: &str) -> Result<Pattern, PatternError> {
+        Pattern::anchored(text.to_string(), text, 0, Some(0), false)
+    }
+
+    /// Parses `text` as the end of the address, without the anchors and fixed positions of `parse`
+    pub fn suffix(text: &str) -> Result<Pattern, PatternError> {
+        Pattern::anchored(format!("{}$", text), text, 0, None, true)
+    }
+
+    /// Compiles the pattern `body`, found at `offset` in `text`
+    /// It starts at `start_anchor` or, if there is none, ends at the end of the address
+    fn anchored(text: String, body: &str, offset: usize, start_anchor: Option<usize>, end_anchor: bool) -> Result<Pattern, PatternError> {
+        let (mut classes, positions) = parse_classes(body, offset)?;
+        if classes.is_empty() {
+            return Err(PatternError::Empty);
+        }
+        if classes.len() > ADDRESS_LEN {
+            return Err(PatternError::TooLong { length: classes.len() });
+        }
+        let start = start_anchor.unwrap_or(ADDRESS_LEN - classes.len());
+        // `@N:` takes any index, so the end may not even fit in a usize
+        let end = start.checked_add(classes.len()).filter(|&end| end <= ADDRESS_LEN);
+        if start_anchor.is_some() && end_anchor && end != Some(ADDRESS_LEN) {
+            return Err(PatternError::WrongLength);
+        }
+        let Some(end) = end else {
+            return Err(PatternError::OutOfBounds { start });
+        };
+        if end == ADDRESS_LEN {
+            let last = classes.last_mut().unwrap();
+            *last &= last_symbols();
+            if *last == 0 {
+                return Err(PatternError::UnreachableLastChar { position: *positions.last().unwrap() });
+            }
+        }
+        Ok(Pattern::compile(text.to_ascii_uppercase(), start, classes))
+    }
+
+    fn compile(text: String, start: usize, classes: Vec<u32>) -> Pattern {
+        let mut public_key_mask = [0u64; 4];
+        let mut public_key_bits = [0u64; 4];
+        let mut checksum_mask = 0u32;
+        let mut checksum_bits = 0u32;
+        let mut inexact = Vec::new();
+        for (i, &class) in classes.iter().enumerate() {
+            let (mask, bits) = common_bits(class);
+            if class.count_ones() != 1 << (5 - mask.count_ones()) {
+                inexact.push(start + i);
+            }
+            for j in (0..5).filter(|j| mask >> (4 - j) & 1 == 1) {
+                let bit = (start + i) * 5 + j;
+                let value = bits >> (4 - j) & 1;
+                if bit < PUBLIC_KEY_BITS {
+                    let shift = 63 - bit % 64;
+                    public_key_mask[bit / 64] |= 1 << shift;
+                    public_key_bits[bit / 64] |= (value as u64) << shift;
+                } else if bit < ADDRESS_BITS {
+                    let shift = 31 - (bit - PUBLIC_KEY_BITS);
+                    checksum_mask |= 1 << shift;
+                    checksum_bits |= (value as u32) << shift;
+                }
+                // the padding bits of the last character are always zero
+            }
+        }
+        Pattern { text, start, classes, public_key_mask, public_key_bits, checksum_mask, checksum_bits, inexact }
+    }
+
+    /// Index of the first address character covered by the pattern
+    pub fn start(&self) -> usize {
+        self.start
+    }
+
+    /// Index one past the last address character covered by the pattern
+    pub fn end(&self) -> usize {
+        self.start + self.classes.len()
+    }
+
+    /// Allowed symbols for every covered character, one bit per symbol
+    pub fn classes(&self) -> &[u32] {
+        &self.classes
+    }
+
+    /// Returns the only symbol allowed at the given address index, if there is exactly one
+    pub fn symbol(&self, index: usize) -> Option<u8> {
+        let class = *self.classes.get(index.checked_sub(self.start)?)?;
+        if class.count_ones() == 1 {
+            Some(class.trailing_zeros() as u8)
+        } else {
+            None
+        }
+    }
+
+    /// The pattern as a plain prefix, if it is one
+    pub fn literal_prefix(&self) -> Option<String> {
+        if self.start != 0 {
+            return None;
+        }
+        (0..self.end()).map(|i| self.symbol(i).map(|s| ALPHABET_CHARS[s as usize] as char)).collect()
+    }
+
+    /// True if the pattern constrains characters which depend on the checksum
+    pub fn needs_checksum(&self) -> bool {
+        self.checksum_mask != 0 || self.inexact.last().is_some_and(|&i| i >= PUBLIC_KEY_CHARS)
+    }
+
+    /// Checks the characters which depend on the public key alone
+    pub fn matches_public_key(&self, public_key: &[u8; 32]) -> bool {
+        let words = public_key_words(public_key);
+        (0..4).all(|i| words[i] & self.public_key_mask[i] == self.public_key_bits[i])
+            && self.inexact.iter().take_while(|&&i| i < PUBLIC_KEY_CHARS).all(|&i| self.allows(i, symbol_at(public_key, i)))
+    }
+
+    /// Checks the characters which depend on the checksum, `address` must include the checksum
+    pub fn matches_checksum(&self, address: &[u8]) -> bool {
+        let checksum = u32::from_be_bytes(address[PUBLIC_KEY_LEN..PUBLIC_KEY_LEN + ADDRESS_CHECKSUM_LEN].try_into().unwrap());
+        checksum & self.checksum_mask == self.checksum_bits
+            && self.inexact.iter().skip_while(|&&i| i < PUBLIC_KEY_CHARS).all(|&i| self.allows(i, symbol_at(address, i)))
+    }
+
+    fn allows(&self, index: usize, symbol: u8) -> bool {
+        self.classes[index - self.start] >> symbol & 1 == 1
+    }
+}
+
+impl FromStr for Pattern {
+    type Err = PatternError;
+
+    fn from_str(text: &str) -> Result<Pattern, PatternError> {
+        Pattern::parse(text)
+    }
+}
+
+impl fmt::Display for Pattern {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+fn public_key_words(public_key: &[u8; 32]) -> [u64; 4] {
+    let mut words = [0u64; 4];
+    for (word, bytes) in words.iter_mut().zip(public_key.chunks_exact(8)) {
+        *word = u64::from_be_bytes(bytes.try_into().unwrap());
+    }
+    words
+}
+
+/// Returns the bits on which all symbols of the class agree, and their values
+fn common_bits(class: u32) -> (u8, u8) {
+    let (mut ones, mut zeros) = (0x1fu8, 0x1fu8);
+    for symbol in (0..32u8).filter(|&s| class >> s & 1 == 1) {
+        ones &= symbol;
+        zeros &= !symbol;
+    }
+    (ones | zeros, ones)
+}
+
+/// Symbols which the last character of an address can take
+fn last_symbols() -> u32 {
+    LAST_CHARS.bytes().filter_map(symbol_value).fold(0, |mask, s| mask | 1 << s)
+}
+
+/// Characters which are easily mistaken for one in the address alphabet
+fn lookalike(character: char) -> Option<char> {
+    match character {
+        '0' => Some('O'),
+        '1' => Some('I'),
+        '8' => Some('B'),
+        _ => None,
+    }
+}
+
+fn symbol_bit(character: char, position: usize) -> Result<u32, PatternError> {
+    u8::try_from(character.to_ascii_uppercase()).ok().and_then(symbol_value)
+        .map(|s| 1 << s)
+        .ok_or(PatternError::InvalidCharacter { character, position })
+}
+
+/// Parses the pattern body into one class per address character
+/// Also returns where each class starts in the whole pattern, `offset` being the position of the body
+fn parse_classes(body: &str, offset: usize) -> Result<(Vec<u32>, Vec<usize>), PatternError> {
+    let mut classes = Vec::new();
+    let mut positions = Vec::new();
+    let mut chars = body.char_indices().map(|(i, c)| (i + offset, c));
+    while let Some((position, character)) = chars.next() {
+        let class = match character {
+            '?' => ANY,
+            '[' => {
+                let mut class = 0;
+                loop {
+                    match chars.next() {
+                        Some((_, ']')) => break,
+                        Some((i, c)) => class |= symbol_bit(c, i)?,
+                        None => return Err(PatternError::UnclosedClass { position }),
+                    }
+                }
+                if class == 0 {
+                    return Err(PatternError::EmptyClass { position });
+                }
+                class
+            }
+            c => symbol_bit(c, position)?,
+        };
+        classes.push(class);
+        positions.push(position);
+    }
+    Ok((classes, positions))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_errors() {
+        for (text, error) in [
+            ("", PatternError::Empty),
+            ("^", PatternError::Empty),
+            ("@3:", PatternError::Empty),
+            ("AB0", PatternError::InvalidCharacter { character: '0', position: 2 }),
+            ("@4:A1", PatternError::InvalidCharacter { character: '1', position: 4 }),
+            ("A[B8]", PatternError::InvalidCharacter { character: '8', position: 3 }),
+            ("A[BC", PatternError::UnclosedClass { position: 1 }),
+            ("A[]", PatternError::EmptyClass { position: 1 }),
+            ("@x:A", PatternError::InvalidPosition),
+            ("@3A", PatternError::InvalidPosition),
+            ("@-1:A", PatternError::InvalidPosition),
+            (&"A".repeat(59), PatternError::TooLong { length: 59 }),
+            ("@50:ABCDEFGHI", PatternError::OutOfBounds { start: 50 }),
+            ("^AB$", PatternError::WrongLength),
+            ("AB$", PatternError::UnreachableLastChar { position: 1 }),
+            ("A[BCD]$", PatternError::UnreachableLastChar { position: 1 }),
+        ] {
+            assert_eq!(Pattern::parse(text).unwrap_err(), error, "{}", text);
+        }
+        assert_eq!(Pattern::prefix("^A").unwrap_err(), PatternError::InvalidCharacter { character: '^', position: 0 });
+        assert_eq!(Pattern::suffix("A$").unwrap_err(), PatternError::InvalidCharacter { character: '$', position: 1 });
+    }
+
+    #[test]
+    fn lookalike_suggestion() {
+        let error = Pattern::parse("A0").unwrap_err().to_string();
+        assert!(error.ends_with("did you mean 'O'?"), "{}", error);
+    }
+
+    #[test]
+    fn normalized() {
+        let pattern = Pattern::parse("^ab[ce]?").unwrap();
+        assert_eq!(pattern.to_string(), "^AB[CE]?");
+        assert_eq!((pattern.start(), pattern.end()), (0, 4));
+        assert_eq!(pattern.literal_prefix(), None);
+        assert_eq!(Pattern::parse("abc").unwrap().literal_prefix().as_deref(), Some("ABC"));
+        // The last character is narrowed down to the symbols it can take
+        assert_eq!(Pattern::parse("[AB]$").unwrap().symbol(ADDRESS_LEN - 1), Some(0));
+    }
+
+    #[test]
+    fn fixed_position_past_the_end() {
+        assert_eq!(Pattern::parse("@57:AB").unwrap_err(), PatternError::OutOfBounds { start: 57 });
+        assert_eq!(Pattern::parse("@58:A").unwrap_err(), PatternError::OutOfBounds { start: 58 });
+        assert_eq!(Pattern::parse("@57:A").unwrap().end(), ADDRESS_LEN);
+    }
+
+    #[test]
+    fn huge_fixed_position() {
+        let text = format!("@{}:A", usize::MAX);
+        assert_eq!(Pattern::parse(&text).unwrap_err(), PatternError::OutOfBounds { start: usize::MAX });
+    }
+}
@@ -0,0 +1,235 @@
+//! Python bindings, built as the `algorand_vanity` extension module with the `python` feature
+
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::Duration;
+use pyo3::exceptions::{PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::crypto::{self, SEED_LEN};
+use crate::mnemonic;
+use crate::pattern::Pattern;
+use crate::searcher::{self, Canceller};
+
+/// How long a search waits for matches before checking for Ctrl-C
+const SIGNAL_INTERVAL: Duration = Duration::from_millis(100);
+
+fn value_error(err: impl std::fmt::Display) -> PyErr {
+    PyValueError::new_err(err.to_string())
+}
+
+fn to_array(bytes: &[u8], name: &str) -> PyResult<[u8; 32]> {
+    bytes.try_into().map_err(|_| value_error(format!("{} must be 32 bytes, got {}", name, bytes.len())))
+}
+
+/// An Algorand account: an Ed25519 key pair with its address and mnemonic
+#[pyclass(frozen, module = "algorand_vanity")]
+#[derive(Clone)]
+struct KeyPair(crypto::KeyPair);
+
+#[pymethods]
+impl KeyPair {
+    /// The key pair of a 32 byte private key seed
+    #[staticmethod]
+    fn from_seed(seed: &[u8]) -> PyResult<KeyPair> {
+        Ok(KeyPair(crypto::KeyPair::from_seed(to_array(seed, "The seed")?)))
+    }
+
+    /// The key pair of a 25 word mnemonic
+    #[staticmethod]
+    fn from_mnemonic(phrase: &str) -> PyResult<KeyPair> {
+        Ok(KeyPair(crypto::KeyPair::from_seed(mnemonic::to_key(phrase).map_err(value_error)?)))
+    }
+
+    #[getter]
+    fn secret_key<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.secret_key)
+    }
+
+    #[getter]
+    fn public_key<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.public_key)
+    }
+
+    #[getter]
+    fn address(&self) -> String {
+        self.0.address()
+    }
+
+    #[getter]
+    fn mnemonic(&self) -> String {
+        mnemonic::from_key(&self.0.secret_key)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("KeyPair(address='{}')", self.0.address())
+    }
+}
+
+/// An account found by a search
+#[pyclass(frozen, get_all, module = "algorand_vanity")]
+struct Match {
+    /// The pattern the address matches
+    pattern: String,
+    key_pair: KeyPair,
+    address: String,
+    mnemonic: String,
+    /// Keys tried by the whole search until the match was found
+    attempts: u64,
+    /// Seconds from the start of the search until the match was found
+    elapsed: f64,
+}
+
+impl From<searcher::Match> for Match {
+    fn from(found: searcher::Match) -> Match {
+        Match {
+            pattern: found.pattern.to_string(),
+            address: found.address(),
+            mnemonic: found.mnemonic(),
+            key_pair: KeyPair(found.key_pair),
+            attempts: found.attempts,
+            elapsed: found.elapsed.as_secs_f64(),
+        }
+    }
+}
+
+#[pymethods]
+impl Match {
+    fn __repr__(&self) -> String {
+        format!("Match(pattern='{}', address='{}')", self.pattern, self.address)
+    }
+}
+
+/// A search for addresses matching any of the patterns, run on CPU threads by `run`
+///
+/// `pattern_type` is one of "pattern", "prefix" or "suffix". Without `count`, `max_time` or `max_attempts`
+/// the search only ends when cancelled, `count=0` is the same as no count.
+/// `cancel` may be called from any thread while `run` is waiting.
+#[pyclass(module = "algorand_vanity")]
+struct Searcher {
+    searcher: Option<searcher::Searcher>,
+    canceller: Canceller,
+}
+
+#[pymethods]
+impl Searcher {
+    #[new]
+    #[pyo3(signature = (patterns, *, pattern_type = "pattern", count = None, threads = None, max_time = None, max_attempts = None))]
+    fn new(
+        patterns: Vec<String>,
+        pattern_type: &str,
+        count: Option<usize>,
+        threads: Option<usize>,
+        max_time: Option<f64>,
+        max_attempts: Option<u64>,
+    ) -> PyResult<Searcher> {
+        if patterns.is_empty() {
+            return Err(value_error("at least one pattern is required"));
+        }
+        let parse = match pattern_type {
+            "pattern" => Pattern::parse,
+            "prefix" => Pattern::prefix,
+            "suffix" => Pattern::suffix,
+            _ => return Err(value_error(format!("Unknown pattern type {}, expected pattern, prefix or suffix", pattern_type))),
+        };
+        let patterns = patterns.iter()
+            .map(|text| parse(text).map_err(|err| value_error(format!("Invalid pattern {}: {}", text, err))))
+            .collect::<PyResult<Vec<Pattern>>>()?;
+        let mut searcher = searcher::Searcher::new(patterns).map_err(value_error)?;
+        if let Some(count) = count {
+            searcher = searcher.count(count);
+        }
+        if let Some(threads) = threads {
+            searcher = searcher.threads(threads);
+        }
+        if let Some(max_time) = max_time {
+            searcher = searcher.max_time(Duration::try_from_secs_f64(max_time).map_err(value_error)?);
+        }
+        if let Some(max_attempts) = max_attempts {
+            searcher = searcher.max_attempts(max_attempts);
+        }
+        Ok(Searcher { canceller: searcher.canceller(), searcher: Some(searcher) })
+    }
+
+    /// Searches until a limit is reached or the search is cancelled and returns the matches found
+    /// Other Python threads keep running meanwhile, Ctrl-C cancels the search. A searcher runs only once.
+    /// Raises a RuntimeError if the search stops on an error.
+    fn run(slf: &Bound<'_, Self>) -> PyResult<Vec<Match>> {
+        let py = slf.py();
+        let searcher = slf.borrow_mut().searcher.take()
+            .ok_or_else(|| value_error("The search has already been run"))?;
+        let mut search = searcher.start();
+        let mut matches = Vec::new();
+        loop {
+            match py.allow_threads(|| search.next_timeout(SIGNAL_INTERVAL)) {
+                Ok(found) => matches.push(Match::from(found)),
+                Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => break,
+            }
+            if let Err(err) = py.check_signals() {
+                search.cancel();
+                return Err(err);
+            }
+        }
+        py.allow_threads(|| search.wait()).map_err(PyRuntimeError::new_err)?;
+        Ok(matches)
+    }
+
+    fn cancel(&self) {
+        self.canceller.cancel();
+    }
+}
+
+/// Searches for addresses matching any of the patterns, see `Searcher`
+#[pyfunction]
+#[pyo3(signature = (patterns, *, pattern_type = "pattern", count = Some(1), threads = None, max_time = None, max_attempts = None))]
+fn search(
+    py: Python<'_>,
+    patterns: Vec<String>,
+    pattern_type: &str,
+    count: Option<usize>,
+    threads: Option<usize>,
+    max_time: Option<f64>,
+    max_attempts: Option<u64>,
+) -> PyResult<Vec<Match>> {
+    let searcher = Bound::new(py, Searcher::new(patterns, pattern_type, count, threads, max_time, max_attempts)?)?;
+    Searcher::run(&searcher)
+}
+
+/// The address of a 32 byte public key
+#[pyfunction]
+fn encode_address(public_key: &[u8]) -> PyResult<String> {
+    Ok(crypto::encode_address(&to_array(public_key, "The public key")?))
+}
+
+/// The public key of an address, verifying its checksum
+#[pyfunction]
+fn decode_address<'py>(py: Python<'py>, address: &str) -> PyResult<Bound<'py, PyBytes>> {
+    Ok(PyBytes::new(py, &crypto::decode_address(address).map_err(value_error)?))
+}
+
+/// The 25 word mnemonic of a 32 byte private key seed
+#[pyfunction]
+fn mnemonic_from_key(key: &[u8]) -> PyResult<String> {
+    Ok(mnemonic::from_key(&to_array(key, "The key")?))
+}
+
+/// The 32 byte private key seed of a 25 word mnemonic
+#[pyfunction]
+fn mnemonic_to_key<'py>(py: Python<'py>, phrase: &str) -> PyResult<Bound<'py, PyBytes>> {
+    Ok(PyBytes::new(py, &mnemonic::to_key(phrase).map_err(value_error)?))
+}
+
+#[pymodule]
+fn algorand_vanity(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("SEED_LEN", SEED_LEN)?;
+    m.add_class::<KeyPair>()?;
+    m.add_class::<Match>()?;
+    m.add_class::<Searcher>()?;
+    m.add_function(wrap_pyfunction!(search, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_address, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_address, m)?)?;
+    m.add_function(wrap_pyfunction!(mnemonic_from_key, m)?)?;
+    m.add_function(wrap_pyfunction!(mnemonic_to_key, m)?)?;
+    Ok(())
+}
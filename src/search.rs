@@ -0,0 +1,266 @@
+use std::io::{stdout, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use serde_json::{json, Value};
+
+use crate::checkpoint::Checkpointer;
+use crate::crypto::{hex, KeyPair};
+use crate::difficulty::{format_duration, Difficulty};
+use crate::mnemonic;
+use crate::pattern::Pattern;
+use crate::seeds::Streams;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Backend {
+    Cpu,
+    Gpu,
+    /// Workers connected to a coordinator
+    Distributed,
+}
+
+impl Backend {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Backend::Cpu => "cpu",
+            Backend::Gpu => "gpu",
+            Backend::Distributed => "distributed",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Format {
+    /// Human readable text with a progress line
+    Text,
+    /// One JSON object per match on stdout, progress as NDJSON events on stderr
+    Json,
+}
+
+/// When to stop searching
+#[derive(Clone, Debug, Default)]
+pub struct Limits {
+    /// Number of distinct matches to find, `None` searches until another limit is reached
+    pub count: Option<usize>,
+    pub max_time: Option<Duration>,
+    pub max_attempts: Option<u64>,
+    /// Stops the search once set, from another thread
+    pub cancel: Option<Arc<AtomicBool>>,
+}
+
+impl Limits {
+    pub fn enough_matches(&self, matches: usize) -> bool {
+        self.count.is_some_and(|count| matches >= count)
+    }
+
+    /// True if the time or attempt limit was reached or the search was cancelled
+    pub fn exhausted(&self, attempts: u64, elapsed: Duration) -> bool {
+        self.max_time.is_some_and(|max_time| elapsed >= max_time)
+            || self.max_attempts.is_some_and(|max_attempts| attempts >= max_attempts)
+            || self.cancel.as_ref().is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+    }
+}
+
+/// Everything a backend needs to run a search besides the patterns
+pub struct Job<'a> {
+    pub limits: Limits,
+    pub reporter: &'a dyn Reporter,
+    pub streams: Streams,
+    /// Holds the state to continue from and saves the progress
+    pub checkpointer: Checkpointer,
+}
+
+/// Outcome of a finished search
+#[derive(Copy, Clone, Debug)]
+pub struct Summary {
+    pub matches: usize,
+    pub attempts: u64,
+    pub elapsed: Duration,
+}
+
+impl Summary {
+    pub fn keys_per_second(&self) -> f64 {
+        self.attempts as f64 / self.elapsed.as_secs_f64()
+    }
+}
+
+/// Receives the progress and results of a search from a backend
+pub trait Reporter {
+    fn started(&self, device: &str);
+    fn progress(&self, attempts: u64, elapsed: Duration, keys_per_second: f64, difficulty: &Difficulty);
+    /// `index` is the position of `pattern` among the patterns of the search
+    fn found(&self, index: usize, pattern: &Pattern, kp: &KeyPair, attempts: u64, elapsed: Duration);
+    /// A hit reported by the GPU did not survive the CPU check and was discarded
+    fn kernel_fault(&self, number: usize, fault: &str);
+    /// Something went wrong without stopping the search
+    fn warning(&self, message: &str);
+    /// The search ran into its time or attempt limit, or was cancelled, before finding enough matches
+    fn stopped(&self, summary: &Summary);
+    /// Something which doesn't fit any of the other events happened, like a worker joining
+    fn info(&self, _message: &str) {}
+}
+
+/// Shows the progress and results of a search on the terminal
+///
+/// In the JSON format every match is a single line on stdout, while everything else
+/// is written to stderr as one event object per line, told apart by its `event` field
+pub struct Console {
+    format: Format,
+    /// Only report matches
+    quiet: bool,
+    backend: Backend,
+}
+
+impl Console {
+    pub fn new(format: Format, quiet: bool, backend: Backend) -> Console {
+        Console { format, quiet, backend }
+    }
+
+    fn event(&self, event: Value) {
+        eprintln!("{}", event);
+    }
+}
+
+impl Reporter for Console {
+    fn started(&self, device: &str) {
+        if self.quiet {
+            return;
+        }
+        match self.format {
+            Format::Text => println!("Search started on {}!", device),
+            Format::Json => self.event(json!({
+                "event": "started",
+                "backend": self.backend.name(),
+                "device": device,
+            })),
+        }
+    }
+
+    fn progress(&self, attempts: u64, elapsed: Duration, keys_per_second: f64, difficulty: &Difficulty) {
+        if self.quiet {
+            return;
+        }
+        match self.format {
+            Format::Text => {
+                print!("\rAvg. key search rate: {:.3}KK/s | {}   ", keys_per_second / 1000.0, difficulty.progress(attempts, keys_per_second));
+                stdout().flush().unwrap();
+            }
+            Format::Json => self.event(json!({
+                "event": "progress",
+                "backend": self.backend.name(),
+                "attempts": attempts,
+                "elapsed_seconds": elapsed.as_secs_f64(),
+                "keys_per_second": keys_per_second,
+                "chance": difficulty.chance_after(attempts as f64),
+                "expected_seconds": difficulty.expected_attempts() / keys_per_second,
+            })),
+        }
+    }
+
+    fn found(&self, _index: usize, pattern: &Pattern, kp: &KeyPair, attempts: u64, elapsed: Duration) {
+        match self.format {
+            Format::Text => {
+                if !self.quiet {
+                    println!();
+                }
+                println!("Match found!");
+                println!("Pattern: {}", pattern);
+                println!("Address: {}", kp.address());
+                println!("Mnemonic: {}", mnemonic::from_key(&kp.secret_key));
+                println!("Time: {}s", elapsed.as_secs());
+            }
+            Format::Json => {
+                println!("{}", json!({
+                    "address": kp.address(),
+                    "public_key": hex(&kp.public_key),
+                    "mnemonic": mnemonic::from_key(&kp.secret_key),
+                    "pattern": pattern.to_string(),
+                    "attempts": attempts,
+                    "elapsed_seconds": elapsed.as_secs_f64(),
+                    "backend": self.backend.name(),
+                }));
+                // lets readers of the events follow the matches without the keys
+                if !self.quiet {
+                    self.event(json!({
+                        "event": "found",
+                        "backend": self.backend.name(),
+                        "address": kp.address(),
+                        "pattern": pattern.to_string(),
+                        "attempts": attempts,
+                        "elapsed_seconds": elapsed.as_secs_f64(),
+                    }));
+                }
+            }
+        }
+    }
+
+    fn kernel_fault(&self, number: usize, fault: &str) {
+        match self.format {
+            Format::Text => {
+                if !self.quiet {
+                    println!();
+                }
+                eprintln!("Kernel fault #{}: {}, discarding the hit", number, fault);
+            }
+            Format::Json => self.event(json!({
+                "event": "kernel_fault",
+                "backend": self.backend.name(),
+                "number": number,
+                "message": fault,
+            })),
+        }
+    }
+
+    fn warning(&self, message: &str) {
+        match self.format {
+            Format::Text => {
+                if !self.quiet {
+                    println!();
+                }
+                eprintln!("Warning: {}", message);
+            }
+            Format::Json => self.event(json!({
+                "event": "warning",
+                "backend": self.backend.name(),
+                "message": message,
+            })),
+        }
+    }
+
+    fn stopped(&self, summary: &Summary) {
+        if self.quiet {
+            return;
+        }
+        match self.format {
+            Format::Text => {
+                println!();
+                println!("Stopped after {} attempts in {}, {} matches found",
+                    summary.attempts, format_duration(summary.elapsed.as_secs_f64()), summary.matches);
+            }
+            Format::Json => self.event(json!({
+                "event": "stopped",
+                "backend": self.backend.name(),
+                "matches": summary.matches,
+                "attempts": summary.attempts,
+                "elapsed_seconds": summary.elapsed.as_secs_f64(),
+            })),
+        }
+    }
+
+    fn info(&self, message: &str) {
+        if self.quiet {
+            return;
+        }
+        match self.format {
+            Format::Text => {
+                println!();
+                println!("{}", message);
+            }
+            Format::Json => self.event(json!({
+                "event": "info",
+                "backend": self.backend.name(),
+                "message": message,
+            })),
+        }
+    }
+}
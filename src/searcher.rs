@@ -0,0 +1,294 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use crate::checkpoint::{Checkpoint, Checkpointer};
+use crate::cpu;
+use crate::crypto::KeyPair;
+use crate::difficulty::Difficulty;
+use crate::matcher::Matcher;
+use crate::mnemonic;
+use crate::pattern::{Pattern, PatternError};
+use crate::search::{Job, Limits, Reporter, Summary};
+use crate::seeds::Streams;
+
+/// An account found by a search
+#[derive(Clone)]
+pub struct Match {
+    /// The pattern the address matches
+    pub pattern: Pattern,
+    /// Position of `pattern` among the patterns of the `Searcher`
+    pub index: usize,
+    pub key_pair: KeyPair,
+    /// Keys tried by the whole search until the match was found
+    pub attempts: u64,
+    pub elapsed: Duration,
+}
+
+impl Match {
+    pub fn address(&self) -> String {
+        self.key_pair.address()
+    }
+
+    pub fn mnemonic(&self) -> String {
+        mnemonic::from_key(&self.key_pair.secret_key)
+    }
+}
+
+/// Stops a search from any thread
+#[derive(Clone, Default)]
+pub struct Canceller(Arc<AtomicBool>);
+
+impl Canceller {
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Builds and starts a vanity search on CPU threads, for use in other programs
+///
+/// ```no_run
+/// use algorand_vanity::{Pattern, Searcher};
+///
+/// let pattern = Pattern::prefix("ALGO").unwrap();
+/// for found in Searcher::new(vec![pattern]).unwrap().count(2).start() {
+///     println!("{} {}", found.address(), found.mnemonic());
+/// }
+/// ```
+///
+/// Without a limit the search runs until it is cancelled or its `Search` is dropped.
+pub struct Searcher {
+    patterns: Vec<Pattern>,
+    threads: usize,
+    limits: Limits,
+    streams: Option<Streams>,
+    canceller: Canceller,
+}
+
+impl Searcher {
+    /// A search for addresses matching any of the patterns, on every logical CPU
+    /// Fails with `PatternError::NoPatterns` without any pattern, as such a search could never end on a match
+    pub fn new(patterns: Vec<Pattern>) -> Result<Searcher, PatternError> {
+        if patterns.is_empty() {
+            return Err(PatternError::NoPatterns);
+        }
+        let canceller = Canceller::default();
+        Ok(Searcher {
+            patterns,
+            threads: std::thread::available_parallelism().map_or(1, |threads| threads.get()),
+            limits: Limits { cancel: Some(canceller.0.clone()), ..Limits::default() },
+            streams: None,
+            canceller,
+        })
+    }
+
+    pub fn threads(mut self, threads: usize) -> Searcher {
+        self.threads = threads.max(1);
+        self
+    }
+
+    /// Stops after this many distinct matches, 0 searches until another limit is reached
+    /// as with `--count 0` on the command line and in the C interface
+    pub fn count(mut self, count: usize) -> Searcher {
+        self.limits.count = Some(count).filter(|&count| count > 0);
+        self
+    }
+
+    pub fn max_time(mut self, max_time: Duration) -> Searcher {
+        self.limits.max_time = Some(max_time);
+        self
+    }
+
+    pub fn max_attempts(mut self, max_attempts: u64) -> Searcher {
+        self.limits.max_attempts = Some(max_attempts);
+        self
+    }
+
+    /// Draws the seeds from the given streams instead of a random key, such as those of a `Shard`
+    pub fn streams(mut self, streams: Streams) -> Searcher {
+        self.streams = Some(streams);
+        self
+    }
+
+    /// Cancels the search once started, also when it is run with `run`
+    pub fn canceller(&self) -> Canceller {
+        self.canceller.clone()
+    }
+
+    /// How hard it is to find a match
+    pub fn difficulty(&self) -> Difficulty {
+        Difficulty::of(&self.patterns)
+    }
+
+    /// Starts searching in the background, the matches are read from the returned `Search`
+    pub fn start(self) -> Search {
+        let (tx, rx) = mpsc::channel();
+        let canceller = self.canceller.clone();
+        let thread = std::thread::spawn(move || {
+            let forward = Forward { matches: tx };
+            let job = Job {
+                limits: self.limits,
+                reporter: &forward,
+                streams: self.streams.unwrap_or_else(Streams::random),
+                checkpointer: Checkpointer::new(None, Checkpoint::new(&self.patterns)),
+            };
+            cpu::run(Matcher::new(self.patterns), self.threads, job)
+        });
+        Search { matches: rx, canceller, thread: Some(thread) }
+    }
+
+    /// Searches on the current thread, calling `on_match` for every match as it is found
+    /// Fails if the search stopped on an error, after passing on the matches found until then
+    pub fn run(self, mut on_match: impl FnMut(Match)) -> Result<Summary, String> {
+        let mut search = self.start();
+        for found in search.by_ref() {
+            on_match(found);
+        }
+        search.wait()
+    }
+}
+
+/// A running search, iterating over its matches until it ends
+///
+/// The iteration also ends if the search fails, `wait` then returns the error.
+/// Dropping it cancels the search.
+pub struct Search {
+    matches: Receiver<Match>,
+    canceller: Canceller,
+    thread: Option<JoinHandle<Result<Summary, String>>>,
+}
+
+impl Search {
+    pub fn canceller(&self) -> Canceller {
+        self.canceller.clone()
+    }
+
+    pub fn cancel(&self) {
+        self.canceller.cancel();
+    }
+
+    /// Waits up to `timeout` for the next match
+    /// Fails with `Timeout` if none came in time and with `Disconnected` once the search has ended
+    pub fn next_timeout(&mut self, timeout: Duration) -> Result<Match, RecvTimeoutError> {
+        self.matches.recv_timeout(timeout)
+    }
+
+    /// Waits for the search to end, matches not read by then are dropped
+    /// Fails if the search stopped on an error
+    pub fn wait(mut self) -> Result<Summary, String> {
+        let thread = self.thread.take().unwrap();
+        thread.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    }
+}
+
+impl Iterator for Search {
+    type Item = Match;
+
+    fn next(&mut self) -> Option<Match> {
+        self.matches.recv().ok()
+    }
+}
+
+impl Drop for Search {
+    fn drop(&mut self) {
+        self.canceller.cancel();
+    }
+}
+
+/// Passes the matches of a backend on to a `Search`
+struct Forward {
+    matches: Sender<Match>,
+}
+
+impl Reporter for Forward {
+    fn started(&self, _device: &str) {}
+
+    fn progress(&self, _attempts: u64, _elapsed: Duration, _keys_per_second: f64, _difficulty: &Difficulty) {}
+
+    fn found(&self, index: usize, pattern: &Pattern, kp: &KeyPair, attempts: u64, elapsed: Duration) {
+        let _ = self.matches.send(Match { pattern: pattern.clone(), index, key_pair: *kp, attempts, elapsed });
+    }
+
+    fn kernel_fault(&self, _number: usize, _fault: &str) {}
+
+    fn warning(&self, _message: &str) {}
+
+    fn stopped(&self, _summary: &Summary) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use std::time::Instant;
+    use super::*;
+
+    /// Practically never matches, so only a limit or a cancel ends the search
+    fn never() -> Searcher {
+        Searcher::new(vec![Pattern::prefix("AAAAAAAAAAAA").unwrap()]).unwrap().threads(1)
+    }
+
+    #[test]
+    fn count() {
+        let mut search = Searcher::new(vec![Pattern::prefix("A").unwrap()]).unwrap().threads(2).count(2).start();
+        let addresses: Vec<String> = search.by_ref().map(|found| found.address()).collect();
+        assert_eq!(addresses.len(), 2);
+        assert_eq!(addresses.iter().collect::<HashSet<_>>().len(), 2);
+        assert!(addresses.iter().all(|address| address.starts_with('A')));
+        assert_eq!(search.wait().unwrap().matches, 2);
+    }
+
+    #[test]
+    fn cancel_ends_iteration() {
+        let mut search = never().start();
+        let canceller = search.canceller();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(100));
+            canceller.cancel();
+        });
+        assert_eq!(search.by_ref().count(), 0);
+        assert_eq!(search.wait().unwrap().matches, 0);
+    }
+
+    #[test]
+    fn drop_stops_threads() {
+        let mut search = never().start();
+        let thread = search.thread.take().unwrap();
+        drop(search);
+        let start = Instant::now();
+        while !thread.is_finished() {
+            assert!(start.elapsed() < Duration::from_secs(10), "the search kept running after it was dropped");
+            std::thread::sleep(Duration::from_millis(10));
+        }
+    }
+
+    #[test]
+    fn max_attempts() {
+        let summary = never().max_attempts(1).run(|found| panic!("unexpected match {}", found.address())).unwrap();
+        assert_eq!(summary.matches, 0);
+        assert!(summary.attempts >= 1);
+    }
+
+    #[test]
+    fn max_time() {
+        let summary = never().max_time(Duration::from_millis(100)).run(|found| panic!("unexpected match {}", found.address())).unwrap();
+        assert_eq!(summary.matches, 0);
+        assert!(summary.elapsed >= Duration::from_millis(100));
+    }
+
+    #[test]
+    fn no_patterns() {
+        assert!(matches!(Searcher::new(Vec::new()), Err(PatternError::NoPatterns)));
+    }
+
+    #[test]
+    fn count_zero_is_unlimited() {
+        assert_eq!(never().count(0).limits.count, None);
+        assert_eq!(never().count(3).limits.count, Some(3));
+    }
+}
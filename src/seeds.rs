@@ -0,0 +1,196 @@
+use std::fmt;
+use std::str::FromStr;
+use rand::rngs::OsRng;
+use rand::{RngCore, SeedableRng};
+use rand_chacha::ChaCha20Rng;
+
+use crate::crypto::SEED_LEN;
+
+/// ChaCha20 counts its position in 32-bit words
+const WORDS_PER_SEED: u128 = (SEED_LEN / 4) as u128;
+/// Streams in a block, one for each thread of the worker or shard the block is handed to
+pub const STREAMS_PER_BLOCK: u64 = 1 << 32;
+
+/// Candidate seeds taken from a ChaCha20 keystream
+///
+/// Every seed is the next 32 bytes of the keystream under a 256-bit key drawn from the OS.
+/// As ChaCha20 is a stream cipher, its keystream cannot be told apart from random bytes
+/// without knowing the key, so learning any number of seeds, such as the ones shown for
+/// matches, reveals neither the key nor any other seed of the stream.
+/// Searches running in parallel share the key and each use their own 64-bit stream number,
+/// which keeps their seeds disjoint.
+///
+/// A single 64 byte ChaCha20 block yields two seeds, which is cheap next to the
+/// scalar multiplication every candidate needs anyway.
+pub struct SeedStream {
+    rng: ChaCha20Rng,
+}
+
+impl SeedStream {
+    /// Stream `stream` of the keystream under `key`
+    pub fn new(key: [u8; 32], stream: u64) -> SeedStream {
+        let mut rng = ChaCha20Rng::from_seed(key);
+        rng.set_stream(stream);
+        SeedStream { rng }
+    }
+
+    /// Stream `stream` under `key`, continuing after the first `position` seeds
+    pub fn resume(key: [u8; 32], stream: u64, position: u64) -> SeedStream {
+        let mut seed_stream = SeedStream::new(key, stream);
+        seed_stream.rng.set_word_pos(position as u128 * WORDS_PER_SEED);
+        seed_stream
+    }
+
+    /// A fresh key from the OS, to be shared by all streams of a search
+    pub fn random_key() -> [u8; 32] {
+        let mut key = [0u8; 32];
+        OsRng.fill_bytes(&mut key);
+        key
+    }
+
+    /// Number of seeds taken from the stream so far
+    pub fn position(&self) -> u64 {
+        (self.rng.get_word_pos() / WORDS_PER_SEED) as u64
+    }
+
+    pub fn next_seed(&mut self) -> [u8; SEED_LEN] {
+        let mut seed = [0u8; SEED_LEN];
+        self.rng.fill_bytes(&mut seed);
+        seed
+    }
+
+    /// Fills `seeds` with consecutive seeds, its length should be a multiple of `SEED_LEN`
+    pub fn fill(&mut self, seeds: &mut [u8]) {
+        self.rng.fill_bytes(seeds);
+    }
+}
+
+/// The key and block of stream numbers a search draws its seeds from
+#[derive(Copy, Clone)]
+pub struct Streams {
+    pub key: [u8; 32],
+    /// Stream of the first worker, the other workers of the search use the ones after it
+    pub first: u64,
+}
+
+impl Streams {
+    /// Streams starting at 0 under a fresh key
+    pub fn random() -> Streams {
+        Streams { key: SeedStream::random_key(), first: 0 }
+    }
+
+    /// The `index`th block of `STREAMS_PER_BLOCK` streams under `key`
+    ///
+    /// Blocks never overlap, so searches using different blocks of the same key never test the same seed
+    /// as long as each runs fewer than `STREAMS_PER_BLOCK` workers.
+    pub fn block(key: [u8; 32], index: u64) -> Streams {
+        Streams { key, first: index * STREAMS_PER_BLOCK }
+    }
+
+    /// Stream number of the worker with the given index
+    pub fn number(&self, worker: usize) -> u64 {
+        self.first + worker as u64
+    }
+
+    /// Seed stream of the worker with the given index, continuing after `position` seeds
+    pub fn open(&self, worker: usize, position: u64) -> SeedStream {
+        SeedStream::resume(self.key, self.number(worker), position)
+    }
+}
+
+/// One of several processes splitting a search between them, written as `i/N` counting from 1
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Shard {
+    pub index: u64,
+    pub count: u64,
+}
+
+impl Shard {
+    /// The block of streams of this shard, shards of the same key never share a stream
+    pub fn streams(&self, key: [u8; 32]) -> Streams {
+        Streams::block(key, self.index - 1)
+    }
+}
+
+impl FromStr for Shard {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Shard, String> {
+        let invalid = || format!("Expected a shard as i/N with 1 <= i <= N, got {}", text);
+        let (index, count) = text.split_once('/').ok_or_else(invalid)?;
+        let shard = Shard {
+            index: index.trim().parse().map_err(|_| invalid())?,
+            count: count.trim().parse().map_err(|_| invalid())?,
+        };
+        if shard.index == 0 || shard.index > shard.count || shard.count > STREAMS_PER_BLOCK {
+            return Err(invalid());
+        }
+        Ok(shard)
+    }
+}
+
+impl fmt::Display for Shard {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.index, self.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+    use super::*;
+
+    #[test]
+    fn shards_are_disjoint() {
+        const SHARDS: u64 = 4;
+        const THREADS: usize = 3;
+        const SEEDS: usize = 500;
+        let key = SeedStream::random_key();
+        let shards: Vec<Streams> = (1..=SHARDS)
+            .map(|index| Shard { index, count: SHARDS }.streams(key))
+            .collect();
+
+        let mut numbers = HashSet::new();
+        for streams in &shards {
+            for thread in 0..THREADS {
+                assert!(numbers.insert(streams.number(thread)), "stream {} is used twice", streams.number(thread));
+            }
+        }
+        // The blocks are far enough apart for any number of threads
+        for pair in shards.windows(2) {
+            assert_eq!(pair[1].first - pair[0].first, STREAMS_PER_BLOCK);
+        }
+
+        let mut seeds = HashSet::new();
+        for streams in &shards {
+            for thread in 0..THREADS {
+                let mut stream = streams.open(thread, 0);
+                for _ in 0..SEEDS {
+                    assert!(seeds.insert(stream.next_seed()), "a seed was drawn twice");
+                }
+            }
+        }
+        assert_eq!(seeds.len(), SHARDS as usize * THREADS * SEEDS);
+    }
+
+    #[test]
+    fn shards_are_reproducible() {
+        let key = SeedStream::random_key();
+        let shard: Shard = "2/3".parse().unwrap();
+        let mut first = shard.streams(key).open(1, 10);
+        let mut second = shard.streams(key).open(1, 0);
+        for _ in 0..10 {
+            second.next_seed();
+        }
+        assert_eq!(first.next_seed(), second.next_seed());
+    }
+
+    #[test]
+    fn parse_shard() {
+        assert_eq!("1/4".parse(), Ok(Shard { index: 1, count: 4 }));
+        assert_eq!("4/4".parse(), Ok(Shard { index: 4, count: 4 }));
+        for text in ["0/4", "5/4", "1", "a/4", "1/0", "-1/4"] {
+            assert!(text.parse::<Shard>().is_err(), "{} was accepted", text);
+        }
+    }
+}
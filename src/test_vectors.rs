@@ -0,0 +1,13 @@
+//! A key known to be right, shared by the self-tests of the backends and the unit tests
+
+pub const SEED: [u8; 32] = [
+    108, 75, 154, 1, 135, 158, 88, 246, 92, 77, 139, 103, 47, 229, 239, 40,
+    220, 185, 84, 75, 117, 203, 247, 26, 91, 7, 240, 156, 134, 212, 162, 234,
+];
+pub const ADDRESS: &str = "HVSAP43LTSSBHXB575COY4BHNNYNUK57ADYS6M7LI2DSXEDXCXDI2C7IC4";
+/// Hex encoded, for the bindings which hand out public keys as text
+#[cfg(all(test, target_arch = "wasm32"))]
+pub const PUBLIC_KEY: &str = "3d6407f36b9ca413dc3dff44ec70276b70da2bbf00f12f33eb46872b907715c6";
+#[cfg(test)]
+pub const MNEMONIC: &str = "horn hard liar trick raise diesel helmet mercy undo net jewel secret inhale steel \
+    prevent slice galaxy hockey admit someone artwork release fence abstract afraid";
@@ -0,0 +1,233 @@
+//! WebAssembly bindings for generating keys in the browser, see `www/` for the Web Worker search
+//!
+//! JavaScript has no threads to share a search between, so `Search` tests keys in batches on the
+//! calling thread, and a search is spread over several Web Workers each running its own `Search`.
+//! Every worker uses its own stream under a session key shared by the search, as the CPU threads do.
+
+use wasm_bindgen::prelude::*;
+
+use crate::crypto;
+use crate::difficulty::Difficulty;
+use crate::matcher::Matcher;
+use crate::mnemonic;
+use crate::pattern::Pattern;
+use crate::seeds::{SeedStream, Streams};
+
+fn to_array(bytes: &[u8], name: &str) -> Result<[u8; 32], JsError> {
+    bytes.try_into().map_err(|_| JsError::new(&format!("{} must be 32 bytes, got {}", name, bytes.len())))
+}
+
+/// Parses the patterns as "pattern", "prefix" or "suffix"
+fn parse_patterns(patterns: Vec<String>, pattern_type: &str) -> Result<Vec<Pattern>, JsError> {
+    let parse = match pattern_type {
+        "pattern" => Pattern::parse,
+        "prefix" => Pattern::prefix,
+        "suffix" => Pattern::suffix,
+        _ => return Err(JsError::new(&format!("Unknown pattern type {}, expected pattern, prefix or suffix", pattern_type))),
+    };
+    patterns.iter()
+        .map(|text| parse(text).map_err(|err| JsError::new(&format!("Invalid pattern {}: {}", text, err))))
+        .collect()
+}
+
+/// An Algorand account: an Ed25519 key pair with its address and mnemonic
+#[wasm_bindgen]
+pub struct KeyPair(crypto::KeyPair);
+
+#[wasm_bindgen]
+impl KeyPair {
+    /// The key pair of a 32 byte private key seed
+    #[wasm_bindgen(js_name = fromSeed)]
+    pub fn from_seed(seed: &[u8]) -> Result<KeyPair, JsError> {
+        Ok(KeyPair(crypto::KeyPair::from_seed(to_array(seed, "The seed")?)))
+    }
+
+    /// The key pair of a 25 word mnemonic
+    #[wasm_bindgen(js_name = fromMnemonic)]
+    pub fn from_mnemonic(phrase: &str) -> Result<KeyPair, JsError> {
+        let key = mnemonic::to_key(phrase).map_err(|err| JsError::new(&err.to_string()))?;
+        Ok(KeyPair(crypto::KeyPair::from_seed(key)))
+    }
+
+    /// A new key pair from the browser's random number generator
+    pub fn random() -> KeyPair {
+        KeyPair(crypto::KeyPair::from_seed(SeedStream::random_key()))
+    }
+
+    #[wasm_bindgen(getter, js_name = secretKey)]
+    pub fn secret_key(&self) -> Vec<u8> {
+        self.0.secret_key.to_vec()
+    }
+
+    #[wasm_bindgen(getter, js_name = publicKey)]
+    pub fn public_key(&self) -> Vec<u8> {
+        self.0.public_key.to_vec()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn address(&self) -> String {
+        self.0.address()
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn mnemonic(&self) -> String {
+        mnemonic::from_key(&self.0.secret_key)
+    }
+}
+
+/// The address of a 32 byte public key
+#[wasm_bindgen(js_name = encodeAddress)]
+pub fn encode_address(public_key: &[u8]) -> Result<String, JsError> {
+    Ok(crypto::encode_address(&to_array(public_key, "The public key")?))
+}
+
+/// The public key of an address, verifying its checksum
+#[wasm_bindgen(js_name = decodeAddress)]
+pub fn decode_address(address: &str) -> Result<Vec<u8>, JsError> {
+    crypto::decode_address(address).map(|key| key.to_vec()).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// The 25 word mnemonic of a 32 byte private key seed
+#[wasm_bindgen(js_name = mnemonicFromKey)]
+pub fn mnemonic_from_key(key: &[u8]) -> Result<String, JsError> {
+    Ok(mnemonic::from_key(&to_array(key, "The key")?))
+}
+
+/// The 32 byte private key seed of a 25 word mnemonic
+#[wasm_bindgen(js_name = mnemonicToKey)]
+pub fn mnemonic_to_key(phrase: &str) -> Result<Vec<u8>, JsError> {
+    mnemonic::to_key(phrase).map(|key| key.to_vec()).map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// A fresh key for the seed streams of a search, to be passed to the `Search` of every worker
+#[wasm_bindgen(js_name = sessionKey)]
+pub fn session_key() -> Vec<u8> {
+    SeedStream::random_key().to_vec()
+}
+
+/// Number of keys a search for the patterns tries on average until it finds a match
+#[wasm_bindgen(js_name = expectedAttempts)]
+pub fn expected_attempts(patterns: Vec<String>, pattern_type: &str) -> Result<f64, JsError> {
+    Ok(Difficulty::of(&parse_patterns(patterns, pattern_type)?).expected_attempts())
+}
+
+/// An account found by a search
+#[wasm_bindgen(getter_with_clone)]
+pub struct Match {
+    /// The pattern the address matches
+    pub pattern: String,
+    pub address: String,
+    pub mnemonic: String,
+    /// Keys tried by this worker until the match was found
+    pub attempts: f64,
+}
+
+/// The part of a search running on one worker
+#[wasm_bindgen]
+pub struct Search {
+    matcher: Matcher,
+    seeds: SeedStream,
+    attempts: u64,
+}
+
+#[wasm_bindgen]
+impl Search {
+    /// Searches stream `worker` under the session `key` for addresses matching any of the patterns
+    #[wasm_bindgen(constructor)]
+    pub fn new(patterns: Vec<String>, pattern_type: &str, key: &[u8], worker: u32) -> Result<Search, JsError> {
+        let streams = Streams { key: to_array(key, "The session key")?, first: 0 };
+        Ok(Search {
+            matcher: Matcher::new(parse_patterns(patterns, pattern_type)?),
+            seeds: streams.open(worker as usize, 0),
+            attempts: 0,
+        })
+    }
+
+    /// Tries up to `attempts` keys and returns the first match, if any
+    pub fn step(&mut self, attempts: u32) -> Option<Match> {
+        for _ in 0..attempts {
+            self.attempts += 1;
+            let kp = crypto::KeyPair::from_seed(self.seeds.next_seed());
+            if let Some(pattern) = self.matcher.find(&kp) {
+                return Some(Match {
+                    pattern: self.matcher.pattern(pattern).to_string(),
+                    address: kp.address(),
+                    mnemonic: mnemonic::from_key(&kp.secret_key),
+                    attempts: self.attempts as f64,
+                });
+            }
+        }
+        None
+    }
+
+    /// Keys tried so far
+    #[wasm_bindgen(getter)]
+    pub fn attempts(&self) -> f64 {
+        self.attempts as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use wasm_bindgen_test::wasm_bindgen_test;
+    use crate::crypto::hex;
+    use crate::test_vectors::{ADDRESS, MNEMONIC, PUBLIC_KEY, SEED};
+    use super::*;
+
+
+    #[wasm_bindgen_test]
+    fn key_pair_from_seed() {
+        let kp = KeyPair::from_seed(&SEED).unwrap();
+        assert_eq!(hex(&kp.public_key()), PUBLIC_KEY);
+        assert_eq!(kp.address(), ADDRESS);
+        assert_eq!(kp.mnemonic(), MNEMONIC);
+        assert_eq!(kp.secret_key(), SEED);
+    }
+
+    #[wasm_bindgen_test]
+    fn key_pair_from_mnemonic() {
+        let kp = KeyPair::from_mnemonic(MNEMONIC).unwrap();
+        assert_eq!(kp.secret_key(), SEED);
+        assert_eq!(kp.address(), ADDRESS);
+    }
+
+    #[wasm_bindgen_test]
+    fn address_round_trip() {
+        let public_key = decode_address(ADDRESS).unwrap();
+        assert_eq!(hex(&public_key), PUBLIC_KEY);
+        assert_eq!(encode_address(&public_key).unwrap(), ADDRESS);
+    }
+
+    #[wasm_bindgen_test]
+    fn mnemonic_round_trip() {
+        assert_eq!(mnemonic_from_key(&SEED).unwrap(), MNEMONIC);
+        assert_eq!(mnemonic_to_key(MNEMONIC).unwrap(), SEED);
+    }
+
+    #[wasm_bindgen_test]
+    fn random_keys_differ() {
+        assert_ne!(KeyPair::random().address(), KeyPair::random().address());
+        assert_ne!(session_key(), session_key());
+    }
+
+    #[wasm_bindgen_test]
+    fn search_finds_prefix() {
+        let key = session_key();
+        let mut search = Search::new(vec!["A".to_string()], "prefix", &key, 0).unwrap();
+        let found = (0..100).find_map(|_| search.step(64)).expect("no address starting with A in 6400 keys");
+        assert!(found.address.starts_with('A'));
+        assert_eq!(KeyPair::from_mnemonic(&found.mnemonic).unwrap().address(), found.address);
+        assert_eq!(search.attempts(), found.attempts);
+    }
+
+    #[wasm_bindgen_test]
+    fn workers_use_their_own_streams() {
+        let key = session_key();
+        let mut first = Search::new(vec!["A".to_string()], "prefix", &key, 0).unwrap();
+        let mut again = Search::new(vec!["A".to_string()], "prefix", &key, 0).unwrap();
+        let mut second = Search::new(vec!["A".to_string()], "prefix", &key, 1).unwrap();
+        let found = (0..100).find_map(|_| first.step(64)).unwrap();
+        assert_eq!((0..100).find_map(|_| again.step(64)).unwrap().address, found.address);
+        assert_ne!((0..100).find_map(|_| second.step(64)).unwrap().address, found.address);
+    }
+}
@@ -1,4 +1,4 @@
-pub const WORDLIST: [&str; 2048] = [
+pub static WORDLIST: [&str; 2048] = [
     "abandon",
         "ability",
         "able",
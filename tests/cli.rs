@@ -0,0 +1,114 @@
+//! Exit codes of the command line tool, as promised by its help and the README
+
+use std::process::{Command, Output};
+
+const SEED: &str = "6c4b9a01879e58f65c4d8b672fe5ef28dcb9544b75cbf71a5b07f09c86d4a2ea";
+const ADDRESS: &str = "HVSAP43LTSSBHXB575COY4BHNNYNUK57ADYS6M7LI2DSXEDXCXDI2C7IC4";
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_algorand-vanity")).args(args).output().unwrap()
+}
+
+fn exit_code(args: &[&str]) -> i32 {
+    run(args).status.code().unwrap()
+}
+
+#[test]
+fn success() {
+    let output = run(&["verify", "--seed", SEED, "HVSA"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains(ADDRESS));
+    assert_eq!(exit_code(&["estimate", "--rate", "1000", "ABC"]), 0);
+}
+
+#[test]
+fn no_match() {
+    assert_eq!(exit_code(&["verify", "--seed", SEED, "ALGO"]), 3);
+    assert_eq!(exit_code(&["search", "--max-time", "1", "-q", "AAAAAAAAAA"]), 3);
+}
+
+#[test]
+fn invalid_arguments() {
+    for args in [
+        &["search", "ABC0"][..],
+        &["search", "--type", "prefix", "^ABC"],
+        &["estimate", "@60:A"],
+        &["estimate", "--rate", "0", "ABC"],
+        &["verify", "--seed", "123", "ABC"],
+        &["verify", "--seed", SEED, "AB$"],
+        &["search", "--session-seed", "zz", "ABC"],
+        &["coordinator", "--listen", "127.0.0.1:0", "--token", "secret", "A[]"],
+        &["bench", "--seconds", "1", "A1"],
+        &["search", "--unknown-flag", "ABC"],
+        &["recover", "--mnemonic", "?", "--address", "HVSAP43LTSSBHXB575COY4BHNNYNUK57ADYS6M7LI2DSXEDXCXDI2C7IC5"],
+        &["recover", "--mnemonic", "?", "--address", "HVSAP43LTSSBHXB575COY4BHNNYNUK57ADYS6M7LI2DSXEDXCXDI2C7IC"],
+    ] {
+        let output = run(args);
+        assert_eq!(output.status.code(), Some(2), "{:?}: {}", args, String::from_utf8_lossy(&output.stderr));
+    }
+}
+
+#[test]
+fn recover() {
+    let mnemonic = "horn hard liar trick raise diesel ? mercy undo net jewel secret inhale steel \
+        prevent slice galaxy hockey admit someone artwork release fence abstract afraid";
+    let output = run(&["recover", "--mnemonic", mnemonic, "--address", &ADDRESS.to_lowercase()]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(String::from_utf8_lossy(&output.stdout).contains("hard liar trick raise diesel helmet mercy"));
+    let other = "HNVCPPGOW2SC2YVDVDICU3YNONSTEFLXDXREHJR2YBEKDC2Z3IUZSC6YGI";
+    assert_eq!(exit_code(&["recover", "--mnemonic", mnemonic, "--address", other]), 3);
+}
+
+#[test]
+fn errors() {
+    let output = run(&["search", "--checkpoint", "/nonexistent/checkpoint.json", "--resume"]);
+    assert_eq!(output.status.code(), Some(1), "{}", String::from_utf8_lossy(&output.stderr));
+    assert_eq!(exit_code(&["verify", "--mnemonic", "abandon abandon"]), 1);
+}
+
+fn json_lines(output: &[u8]) -> Vec<serde_json::Value> {
+    String::from_utf8_lossy(output).lines().map(|line| serde_json::from_str(line).unwrap()).collect()
+}
+
+#[test]
+fn json_output() {
+    let output = run(&["search", "--format", "json", "-n", "0", "--max-time", "2", "--threads", "1", "A"]);
+    assert_eq!(output.status.code(), Some(0), "{}", String::from_utf8_lossy(&output.stderr));
+    let matches = json_lines(&output.stdout);
+    assert!(!matches.is_empty());
+    for found in &matches {
+        assert!(found["address"].as_str().unwrap().starts_with('A'));
+        assert_eq!(found["mnemonic"].as_str().unwrap().split(' ').count(), 25);
+        assert_eq!(found["pattern"], "A");
+        assert!(found["attempts"].as_u64().unwrap() >= 1);
+        assert_eq!(found["backend"], "cpu");
+    }
+
+    let events = json_lines(&output.stderr);
+    let named = |name: &str| events.iter().filter(|event| event["event"] == name).collect::<Vec<_>>();
+    assert_eq!(events[0]["event"], "started");
+    let found = named("found");
+    assert_eq!(found.len(), matches.len());
+    for (event, found) in found.iter().zip(&matches) {
+        assert_eq!(event["address"], found["address"]);
+        assert!(event.get("mnemonic").is_none());
+    }
+    let stopped = named("stopped");
+    assert_eq!(stopped.len(), 1);
+    assert_eq!(stopped[0]["matches"].as_u64(), Some(matches.len() as u64));
+
+    let quiet = run(&["search", "--format", "json", "-q", "--threads", "1", "A"]);
+    assert_eq!(quiet.status.code(), Some(0));
+    assert_eq!(json_lines(&quiet.stdout).len(), 1);
+    assert!(json_lines(&quiet.stderr).is_empty());
+}
+
+#[cfg(not(feature = "gpu"))]
+#[test]
+fn no_gpu() {
+    for args in [&["search", "--backend", "gpu", "ABC"][..], &["devices"], &["bench", "--backend", "gpu", "--seconds", "1"]] {
+        let output = run(args);
+        assert_eq!(output.status.code(), Some(1), "{:?}", args);
+        assert!(String::from_utf8_lossy(&output.stderr).contains("This build has no GPU support, rebuild it with --features gpu"), "{:?}", args);
+    }
+}
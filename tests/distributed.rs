@@ -0,0 +1,80 @@
+//! A distributed search between a coordinator and two workers on localhost
+
+use algorand_vanity::cpu;
+use algorand_vanity::difficulty::Difficulty;
+use algorand_vanity::distributed::{coordinate, work};
+use algorand_vanity::search::{Limits, Reporter, Summary};
+use algorand_vanity::seeds::STREAMS_PER_BLOCK;
+use algorand_vanity::{KeyPair, Matcher, Pattern};
+use std::collections::HashSet;
+use std::net::{TcpListener, TcpStream};
+use std::sync::{Barrier, Mutex};
+use std::time::Duration;
+
+const TOKEN: &str = "7f3c9e1a5b2d4086";
+
+#[derive(Default)]
+struct Recorder {
+    found: Mutex<Vec<String>>,
+    warnings: Mutex<Vec<String>>,
+}
+
+impl Reporter for Recorder {
+    fn started(&self, _device: &str) {}
+    fn progress(&self, _attempts: u64, _elapsed: Duration, _keys_per_second: f64, _difficulty: &Difficulty) {}
+
+    fn found(&self, _index: usize, _pattern: &Pattern, kp: &KeyPair, _attempts: u64, _elapsed: Duration) {
+        self.found.lock().unwrap().push(kp.address());
+    }
+
+    fn kernel_fault(&self, _number: usize, _fault: &str) {}
+
+    fn warning(&self, message: &str) {
+        self.warnings.lock().unwrap().push(message.to_string());
+    }
+
+    fn stopped(&self, _summary: &Summary) {}
+}
+
+#[test]
+fn two_workers() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let coordinator = Recorder::default();
+    let limits = Limits { count: Some(2), max_time: Some(Duration::from_secs(60)), ..Limits::default() };
+    let streams = Mutex::new(Vec::new());
+    // both workers have their blocks before either finds anything
+    let barrier = Barrier::new(2);
+    std::thread::scope(|scope| {
+        let summary = scope.spawn(|| coordinate(listener, TOKEN, vec![Pattern::prefix("A").unwrap()], limits, &coordinator));
+
+        let intruder = work(TcpStream::connect(address).unwrap(), "wrong token", &Recorder::default(), |_, _| {
+            panic!("a worker with the wrong token was given work")
+        });
+        assert!(intruder.is_err());
+
+        let workers: Vec<_> = (0..2).map(|_| scope.spawn(|| {
+            work(TcpStream::connect(address).unwrap(), TOKEN, &Recorder::default(), |patterns, job| {
+                streams.lock().unwrap().push((job.streams.key, job.streams.first));
+                barrier.wait();
+                cpu::run(Matcher::new(patterns), 1, job)
+            })
+        })).collect();
+        for worker in workers {
+            assert!(worker.join().unwrap().is_ok());
+        }
+        assert_eq!(summary.join().unwrap().unwrap().matches, 2);
+    });
+    // the coordinator stopped listening when it returned
+    assert!(TcpStream::connect(address).is_err());
+
+    let found = coordinator.found.into_inner().unwrap();
+    assert_eq!(found.len(), 2);
+    assert_eq!(found.iter().collect::<HashSet<_>>().len(), 2);
+    assert!(found.iter().all(|address| address.starts_with('A')));
+    assert_eq!(coordinator.warnings.into_inner().unwrap().len(), 1);
+
+    let streams = streams.into_inner().unwrap();
+    assert_eq!(streams[0].0, streams[1].0);
+    assert!(streams[0].1.abs_diff(streams[1].1) >= STREAMS_PER_BLOCK);
+}
@@ -0,0 +1,65 @@
+// Vanity search in the browser, spread over Web Workers running the WebAssembly build
+import init, { sessionKey, expectedAttempts } from "./pkg/algorand_vanity.js";
+
+/**
+ * Searches for addresses matching any of the patterns until `count` distinct matches are found.
+ * The keys never leave the browser: they are drawn from its crypto.getRandomValues.
+ *
+ * Options: patternType ("pattern", "prefix" or "suffix"), count (0 for no limit), workers
+ * (defaults to the number of logical CPUs), onMatch(match) for every match as it is found and
+ * onProgress({ attempts, elapsedSeconds, keysPerSecond, expectedAttempts }) about every second.
+ *
+ * Returns { matches, cancel }: matches is a promise of every match found, which resolves once
+ * `count` are found or cancel() is called.
+ */
+export async function search(patterns, options = {}) {
+    const {
+        patternType = "pattern",
+        count = 1,
+        workers = navigator.hardwareConcurrency || 4,
+        onMatch = () => {},
+        onProgress = () => {},
+    } = options;
+    await init();
+    const expected = expectedAttempts(patterns, patternType);
+    const key = sessionKey();
+    const attempts = new Array(workers).fill(0);
+    const found = new Map();
+    const start = performance.now();
+    let threads = [];
+    let timer;
+    let finish;
+    const matches = new Promise((resolve) => { finish = resolve; });
+
+    const cancel = () => {
+        threads.forEach((thread) => thread.terminate());
+        threads = [];
+        clearInterval(timer);
+        finish([...found.values()]);
+    };
+    const report = () => {
+        const total = attempts.reduce((sum, value) => sum + value, 0);
+        const elapsedSeconds = (performance.now() - start) / 1000;
+        onProgress({ attempts: total, elapsedSeconds, keysPerSecond: total / elapsedSeconds, expectedAttempts: expected });
+    };
+
+    for (let worker = 0; worker < workers; worker++) {
+        const thread = new Worker(new URL("./worker.js", import.meta.url), { type: "module" });
+        thread.onmessage = ({ data }) => {
+            if (data.type === "progress") {
+                attempts[worker] = data.attempts;
+            } else if (data.type === "match" && !found.has(data.address)) {
+                const match = { pattern: data.pattern, address: data.address, mnemonic: data.mnemonic };
+                found.set(data.address, match);
+                onMatch(match);
+                if (count > 0 && found.size >= count) {
+                    cancel();
+                }
+            }
+        };
+        thread.postMessage({ patterns, patternType, key, worker });
+        threads.push(thread);
+    }
+    timer = setInterval(report, 1000);
+    return { matches, cancel };
+}
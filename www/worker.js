@@ -0,0 +1,19 @@
+// Runs one part of a search, started by vanity.js with its own stream under the session key
+import init, { Search } from "./pkg/algorand_vanity.js";
+
+// Keys tried between progress messages
+const BATCH = 2048;
+
+onmessage = async ({ data: { patterns, patternType, key, worker } }) => {
+    await init();
+    const search = new Search(patterns, patternType, key, worker);
+    for (;;) {
+        const found = search.step(BATCH);
+        if (found) {
+            postMessage({ type: "match", pattern: found.pattern, address: found.address, mnemonic: found.mnemonic });
+            found.free();
+        } else {
+            postMessage({ type: "progress", attempts: search.attempts });
+        }
+    }
+};